@@ -0,0 +1,363 @@
+use std::fs;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::runner::{InputSource, PartSelection};
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+/// Default number of warm-up runs conducted before measurements are recorded.
+pub const DEFAULT_WARMUP_RUNS: usize = 3;
+/// Default number of measured runs.
+pub const DEFAULT_RUNS: usize = 10;
+/// Default percentage increase in median execution time over the baseline that is flagged as a
+/// regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+/// Options controlling how each day is benchmarked.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BenchOptions {
+    pub warmup_runs: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup_runs: DEFAULT_WARMUP_RUNS,
+            runs: DEFAULT_RUNS,
+        }
+    }
+}
+
+/// Summary statistics for the execution times measured for a single phase (input processing,
+/// Part 1 or Part 2) across the benchmark runs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PhaseStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl PhaseStats {
+    /// Calculates the summary statistics for the given samples. Returns None if there are no
+    /// samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<PhaseStats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean_secs = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        // Sample standard deviation (zero if only a single sample was taken)
+        let stddev_secs = if n > 1 {
+            let sum_sq = sorted
+                .iter()
+                .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+                .sum::<f64>();
+            (sum_sq / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+        Some(PhaseStats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(stddev_secs),
+        })
+    }
+
+    /// Converts the statistics to JSON, with the durations given in nanoseconds.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "mean_ns": self.mean.as_nanos() as u64,
+            "stddev_ns": self.stddev.as_nanos() as u64,
+        })
+    }
+}
+
+/// Holds the benchmark statistics recorded for a single day. Part statistics are None if the part
+/// was not selected to be benchmarked.
+pub struct DayBench {
+    pub day: u64,
+    pub name: &'static str,
+    pub input: String,
+    pub runs: usize,
+    pub input_stats: PhaseStats,
+    pub part1_stats: Option<PhaseStats>,
+    pub part2_stats: Option<PhaseStats>,
+}
+
+impl DayBench {
+    /// Returns the statistics for each benchmarked phase, labelled by phase name.
+    pub fn phases(&self) -> Vec<(&'static str, PhaseStats)> {
+        let mut phases = vec![("input", self.input_stats)];
+        if let Some(stats) = self.part1_stats {
+            phases.push(("part1", stats));
+        }
+        if let Some(stats) = self.part2_stats {
+            phases.push(("part2", stats));
+        }
+        phases
+    }
+
+    /// Prints the benchmark statistics to stdout.
+    pub fn print(&self) {
+        println!("==================================================");
+        println!("AOC 2015 Day {} - \"{}\"", self.day, self.name);
+        println!("Input: {}", self.input);
+        println!("Benchmark runs: {}", self.runs);
+        println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+        println!("            min        median     mean       stddev");
+        for (phase, stats) in self.phases() {
+            println!(
+                "[+] {:<8}{:<11}{:<11}{:<11}{:.2?}",
+                phase_label(phase),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                stats.stddev
+            );
+        }
+        println!("==================================================");
+    }
+
+    /// Converts the benchmark statistics to JSON.
+    pub fn to_json(&self) -> Value {
+        let phases = self
+            .phases()
+            .into_iter()
+            .map(|(phase, stats)| (phase.to_string(), stats.to_json()))
+            .collect::<serde_json::Map<String, Value>>();
+        json!({
+            "day": self.day,
+            "name": self.name,
+            "input": self.input,
+            "runs": self.runs,
+            "phases": phases,
+        })
+    }
+}
+
+/// Represents the change in median execution time for a single phase compared to the baseline.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Comparison {
+    pub day: u64,
+    pub phase: &'static str,
+    pub baseline_median: Duration,
+    pub median: Duration,
+    pub change_percent: f64,
+    pub is_regression: bool,
+}
+
+/// Returns the display label for the given phase name.
+fn phase_label(phase: &str) -> &'static str {
+    match phase {
+        "input" => "Input:",
+        "part1" => "Part 1:",
+        "part2" => "Part 2:",
+        _ => "?:",
+    }
+}
+
+/// Benchmarks the given solution by processing the input and solving the selected parts
+/// repeatedly, after conducting the warm-up runs. The raw input is only read once.
+pub fn bench_day<S: Solution>(
+    source: &InputSource,
+    parts: PartSelection,
+    options: &BenchOptions,
+) -> Result<DayBench, ParseError> {
+    let location = source.location(S::DAY, S::INPUT_FILE);
+    let raw_input = source.read(S::DAY, S::INPUT_FILE)?;
+    let mut input_samples: Vec<Duration> = vec![];
+    let mut part1_samples: Vec<Duration> = vec![];
+    let mut part2_samples: Vec<Duration> = vec![];
+    for run in 0..(options.warmup_runs + options.runs) {
+        let is_warmup = run < options.warmup_runs;
+        // Input processing
+        let start = Instant::now();
+        let input = S::parse(&raw_input).map_err(|err| err.with_file(&location))?;
+        let input_duration = start.elapsed();
+        // Solve part 1
+        let part1_duration = parts.includes_part1().then(|| {
+            let timestamp = Instant::now();
+            std::hint::black_box(S::part1(&input));
+            timestamp.elapsed()
+        });
+        // Solve part 2
+        let part2_duration = parts.includes_part2().then(|| {
+            let timestamp = Instant::now();
+            std::hint::black_box(S::part2(&input));
+            timestamp.elapsed()
+        });
+        if is_warmup {
+            continue;
+        }
+        input_samples.push(input_duration);
+        part1_samples.extend(part1_duration);
+        part2_samples.extend(part2_duration);
+    }
+    Ok(DayBench {
+        day: S::DAY,
+        name: S::NAME,
+        input: location,
+        runs: options.runs,
+        input_stats: PhaseStats::from_samples(&input_samples).unwrap(),
+        part1_stats: PhaseStats::from_samples(&part1_samples),
+        part2_stats: PhaseStats::from_samples(&part2_samples),
+    })
+}
+
+/// Converts the results of a benchmark session to JSON.
+pub fn results_to_json(results: &[DayBench], options: &BenchOptions) -> Value {
+    json!({
+        "warmup_runs": options.warmup_runs,
+        "runs": options.runs,
+        "results": results.iter().map(|r| r.to_json()).collect::<Vec<Value>>(),
+    })
+}
+
+/// Writes the results of a benchmark session to the given file as JSON.
+pub fn write_results(
+    filename: &str,
+    results: &[DayBench],
+    options: &BenchOptions,
+) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&results_to_json(results, options))
+        .map_err(|err| err.to_string())?;
+    fs::write(filename, json + "\n").map_err(|err| format!("{filename}: {err}"))
+}
+
+/// Reads a baseline file previously written by a benchmark session.
+pub fn read_baseline(filename: &str) -> Result<Value, String> {
+    let raw = fs::read_to_string(filename).map_err(|err| format!("{filename}: {err}"))?;
+    serde_json::from_str(&raw).map_err(|err| format!("{filename}: {err}"))
+}
+
+/// Compares the median execution times of the benchmark results against the baseline. Phases
+/// without a matching baseline record (same day, input and phase) are skipped. Phases with a
+/// median execution time increase greater than the threshold (percentage) are flagged as
+/// regressions.
+pub fn compare_to_baseline(
+    results: &[DayBench],
+    baseline: &Value,
+    threshold: f64,
+) -> Vec<Comparison> {
+    let mut comparisons: Vec<Comparison> = vec![];
+    let empty = vec![];
+    let baseline_results = baseline["results"].as_array().unwrap_or(&empty);
+    for result in results {
+        let record = baseline_results.iter().find(|r| {
+            r["day"].as_u64() == Some(result.day)
+                && r["input"].as_str() == Some(result.input.as_str())
+        });
+        let Some(record) = record else {
+            continue;
+        };
+        for (phase, stats) in result.phases() {
+            let Some(baseline_ns) = record["phases"][phase]["median_ns"].as_u64() else {
+                continue;
+            };
+            let baseline_median = Duration::from_nanos(baseline_ns);
+            let change_percent = if baseline_ns == 0 {
+                0.0
+            } else {
+                (stats.median.as_secs_f64() / baseline_median.as_secs_f64() - 1.0) * 100.0
+            };
+            comparisons.push(Comparison {
+                day: result.day,
+                phase,
+                baseline_median,
+                median: stats.median,
+                change_percent,
+                is_regression: change_percent > threshold,
+            });
+        }
+    }
+    comparisons
+}
+
+/// Prints the comparison of the benchmark results against the baseline to stdout.
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64) {
+    println!("==================================================");
+    println!(
+        "Baseline comparison (median, threshold: +{:.1}%)",
+        threshold
+    );
+    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+    for c in comparisons {
+        println!(
+            "[{}] Day {:02} {:<8}{:>10} -> {:<10} ({:+.1}%)",
+            if c.is_regression { "!" } else { "+" },
+            c.day,
+            phase_label(c.phase),
+            format!("{:.2?}", c.baseline_median),
+            format!("{:.2?}", c.median),
+            c.change_percent
+        );
+    }
+    let regressions = comparisons.iter().filter(|c| c.is_regression).count();
+    println!("[*] Regressions: {}", regressions);
+    println!("==================================================");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the summary statistics calculated from a set of samples.
+    #[test]
+    fn test_phase_stats_from_samples() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect::<Vec<Duration>>();
+        let stats = PhaseStats::from_samples(&samples).unwrap();
+        assert_eq!(Duration::from_millis(2), stats.min);
+        assert_eq!(Duration::from_micros(4500), stats.median);
+        assert_eq!(5000, stats.mean.as_micros());
+        assert_eq!(2138, stats.stddev.as_micros());
+        assert_eq!(None, PhaseStats::from_samples(&[]));
+    }
+
+    /// Tests that median execution time increases above the threshold are flagged as regressions.
+    #[test]
+    fn test_compare_to_baseline() {
+        let stats = |ms: u64| PhaseStats {
+            min: Duration::from_millis(ms),
+            median: Duration::from_millis(ms),
+            mean: Duration::from_millis(ms),
+            stddev: Duration::ZERO,
+        };
+        let results = vec![DayBench {
+            day: 7,
+            name: "Some Assembly Required",
+            input: String::from("./input/day07.txt"),
+            runs: 10,
+            input_stats: stats(10),
+            part1_stats: Some(stats(12)),
+            part2_stats: None,
+        }];
+        let baseline = results_to_json(&results, &BenchOptions::default());
+        let results = vec![DayBench {
+            input_stats: stats(11),
+            part1_stats: Some(stats(15)),
+            ..results.into_iter().next().unwrap()
+        }];
+        let comparisons = compare_to_baseline(&results, &baseline, 20.0);
+        assert_eq!(2, comparisons.len());
+        assert!(!comparisons[0].is_regression);
+        assert!(comparisons[1].is_regression);
+        assert_eq!(25, comparisons[1].change_percent.round() as i64);
+    }
+}
@@ -0,0 +1,130 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Not Quite Lisp";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day01.txt";
+pub const PROBLEM_DAY: u64 = 1;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("(())", Some("0"), None),
+    Example::new("()()", Some("0"), None),
+    Example::new("(((", Some("3"), None),
+    Example::new("(()(()(", Some("3"), None),
+    Example::new("))(((((", Some("3"), None),
+    Example::new("())", Some("-1"), None),
+    Example::new("))(", Some("-1"), None),
+    Example::new(")))", Some("-3"), None),
+    Example::new(")())())", Some("-3"), None),
+    Example::new(")", None, Some("1")),
+    Example::new("()())", None, Some("5")),
+];
+
+/// Solution for AOC 2015 Day 1.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 1 input file into the format required by the solver functions.
+/// Returned value is vector of +1 (open parenthesis: '(') or -1 (closed parenthesis: ')') values
+/// determined by the chars given in the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<i64>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 1 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<i64>, ParseError> {
+    let offset = raw_input.len() - raw_input.trim_start().len();
+    let mut values: Vec<i64> = vec![];
+    for (i, c) in raw_input.trim().char_indices() {
+        match c {
+            '(' => values.push(1),
+            ')' => values.push(-1),
+            _ => {
+                let found = c.to_string();
+                return Err(ParseError::at_offset(
+                    raw_input,
+                    offset + i,
+                    "'(' or ')'",
+                    &found,
+                ));
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// Solves AOC 2015 Day 1 Part 1 // Determines the resulting floor by navigating up and down floors
+/// accourding to the input characters (starting on floor 0).
+pub fn solve_part1(input: &[i64]) -> i64 {
+    input.iter().sum::<i64>()
+}
+
+/// Solves AOC 2015 Day 1 Part 2 // Determines the index of the first movement that results in the
+/// basement floor being reached.
+pub fn solve_part2(input: &[i64]) -> usize {
+    let mut floor = 0;
+    for (i, value) in input.iter().enumerate() {
+        floor += value;
+        if floor == -1 {
+            return i + 1;
+        }
+    }
+    panic!("Day 1 Part 2: did not reach the basement floor (-1)!");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 1 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day01_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(232, solution);
+    }
+
+    /// Tests the Day 1 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day01_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(1783, solution);
+    }
+
+    /// Tests the Day 1 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day01_examples() {
+        assert_examples::<Day01>();
+    }
+}
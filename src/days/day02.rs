@@ -0,0 +1,120 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::bespoke::Present;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "I Was Told There Would Be No Math";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day02.txt";
+pub const PROBLEM_DAY: u64 = 2;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("2x3x4", Some("58"), Some("34")),
+    Example::new("1x1x10", Some("43"), Some("14")),
+];
+
+/// Solution for AOC 2015 Day 02.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Present>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 02 input file into the format required by the solver functions.
+/// Returned value is vector of Present structs specified by the input file lines.
+pub fn process_input_file(filename: &str) -> Result<Vec<Present>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 02 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Present>, ParseError> {
+    let mut presents: Vec<Present> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let dims = line
+            .split('x')
+            .map(|elem| elem.parse::<u64>())
+            .collect::<Result<Vec<u64>, _>>();
+        match dims {
+            Ok(dims) if dims.len() == 3 => presents.push(Present::new(dims[0], dims[1], dims[2])),
+            _ => {
+                return Err(ParseError::new(
+                    i + 1,
+                    column,
+                    "<length>x<width>x<height>",
+                    line,
+                ))
+            }
+        }
+    }
+    Ok(presents)
+}
+
+/// Solves AOC 2015 Day 02 Part 1 // Calculates the total amount of wrapping paper (in square feet)
+/// needed to wrap all the presents.
+pub fn solve_part1(presents: &[Present]) -> u64 {
+    presents.iter().map(|pres| pres.paper_needed()).sum()
+}
+
+/// Solves AOC 2015 Day 02 Part 2 // Calculates the total amount of ribbon needed (in feet) to wrap
+/// all the presents.
+pub fn solve_part2(presents: &[Present]) -> u64 {
+    presents.iter().map(|pres| pres.ribbon_needed()).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 02 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day02_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(1588178, solution);
+    }
+
+    /// Tests the Day 02 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day02_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(3783758, solution);
+    }
+
+    /// Tests the Day 02 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day02_examples() {
+        assert_examples::<Day02>();
+    }
+}
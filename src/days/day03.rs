@@ -1,68 +1,88 @@
 use std::collections::HashSet;
 use std::fs;
-use std::time::Instant;
 
 use aoc_utils::cartography::{CardinalDirection, Point2D};
 
-const PROBLEM_NAME: &str = "Perfectly Spherical Houses in a Vacuum";
-const PROBLEM_INPUT_FILE: &str = "./input/day03.txt";
-const PROBLEM_DAY: u64 = 3;
-
-/// Processes the AOC 2015 Day 03 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Perfectly Spherical Houses in a Vacuum";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day03.txt";
+pub const PROBLEM_DAY: u64 = 3;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new(">", Some("2"), None),
+    Example::new("^>v<", Some("4"), Some("3")),
+    Example::new("^v^v^v^v^v", Some("2"), Some("11")),
+    Example::new("^v", None, Some("3")),
+];
+
+/// Solution for AOC 2015 Day 03.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<CardinalDirection>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 03 input file into the format required by the solver functions.
 /// Returned value is vector of cardinal directions indicated by the characters in the input file.
-fn process_input_file(filename: &str) -> Vec<CardinalDirection> {
+pub fn process_input_file(filename: &str) -> Result<Vec<CardinalDirection>, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 03 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<CardinalDirection>, ParseError> {
+    let offset = raw_input.len() - raw_input.trim_start().len();
     let mut directions: Vec<CardinalDirection> = vec![];
-    for c in raw_input.trim().chars() {
+    for (i, c) in raw_input.trim().char_indices() {
         match c {
             '^' => directions.push(CardinalDirection::North),
             '>' => directions.push(CardinalDirection::East),
             'v' => directions.push(CardinalDirection::South),
             '<' => directions.push(CardinalDirection::West),
-            _ => panic!("Bad character in input file: {}", c),
+            _ => {
+                let found = c.to_string();
+                let expected = "'^', '>', 'v' or '<'";
+                return Err(ParseError::at_offset(
+                    raw_input,
+                    offset + i,
+                    expected,
+                    &found,
+                ));
+            }
         }
     }
-    directions
+    Ok(directions)
 }
 
 /// Solves AOC 2015 Day 03 Part 1 // Determines the number of houses that receive at least one
 /// present with only Santa delivering presents.
-fn solve_part1(directions: &[CardinalDirection]) -> usize {
+pub fn solve_part1(directions: &[CardinalDirection]) -> usize {
     let loc_start = Point2D::new(0, 0);
     let mut visited: HashSet<Point2D> = HashSet::from([loc_start]);
     deliver_presents(loc_start, &mut directions.iter(), &mut visited);
@@ -71,7 +91,7 @@ fn solve_part1(directions: &[CardinalDirection]) -> usize {
 
 /// Solves AOC 2015 Day 03 Part 2 // Determines the number of houses that receive at least one
 /// present with Santa and Robo-Santa alternating movements.
-fn solve_part2(directions: &[CardinalDirection]) -> usize {
+pub fn solve_part2(directions: &[CardinalDirection]) -> usize {
     let loc_start = Point2D::new(0, 0);
     let mut visited: HashSet<Point2D> = HashSet::from([loc_start]);
     deliver_presents(loc_start, &mut directions.iter().step_by(2), &mut visited);
@@ -105,11 +125,12 @@ fn deliver_presents(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 03 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day03_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(2572, solution);
     }
@@ -117,8 +138,14 @@ mod test {
     /// Tests the Day 03 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day03_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(2631, solution);
     }
+
+    /// Tests the Day 03 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day03_examples() {
+        assert_examples::<Day03>();
+    }
 }
@@ -1,64 +1,72 @@
 use std::fs;
 use std::str;
-use std::time::Instant;
 
 use md5::{Digest, Md5};
 
-const PROBLEM_NAME: &str = "The Ideal Stocking Stuffer";
-const PROBLEM_INPUT_FILE: &str = "./input/day04.txt";
-const PROBLEM_DAY: u64 = 4;
-
-/// Processes the AOC 2015 Day 04 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "The Ideal Stocking Stuffer";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day04.txt";
+pub const PROBLEM_DAY: u64 = 4;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("abcdef", Some("609043"), None),
+    Example::new("pqrstuv", Some("1048970"), None),
+];
+
+/// Solution for AOC 2015 Day 04.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = String;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 04 input file into the format required by the solver functions.
 /// Returned value is String given in the input file.
-fn process_input_file(filename: &str) -> String {
+pub fn process_input_file(filename: &str) -> Result<String, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
-    String::from(raw_input.trim())
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 04 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<String, ParseError> {
+    Ok(String::from(raw_input.trim()))
 }
 
 /// Solves AOC 2015 Day 04 Part 1 // Determines the lowest positive integer that results in an MD5
 /// hash starting with five zeroes when post-fixed to the secret key.
-fn solve_part1(secret_key: &str) -> u64 {
+pub fn solve_part1(secret_key: &str) -> u64 {
     find_valid_serial_number(secret_key, 5)
 }
 
 /// Solves AOC 2015 Day 04 Part 2 // Determines the lowest positive integer that results in an MD5
 /// hash starting with six zeroes when post-fixed to the secret key.
-fn solve_part2(secret_key: &str) -> u64 {
+pub fn solve_part2(secret_key: &str) -> u64 {
     find_valid_serial_number(secret_key, 6)
 }
 
@@ -95,11 +103,12 @@ fn calculate_md5_hex_result(secret_key: &str, serial: u64) -> String {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 04 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day04_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(254575, solution);
     }
@@ -107,8 +116,14 @@ mod test {
     /// Tests the Day 04 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day04_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(1038736, solution);
     }
+
+    /// Tests the Day 04 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day04_examples() {
+        assert_examples::<Day04>();
+    }
 }
@@ -0,0 +1,136 @@
+use std::fs;
+
+use fancy_regex::Regex; // fancy_regex needed for back-references (not implemented in regex crate)
+use lazy_static::lazy_static;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Doesn't He Have Intern-Elves For This?";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day05.txt";
+pub const PROBLEM_DAY: u64 = 5;
+
+lazy_static! {
+    static ref REGEX_P1_1: Regex = Regex::new(r"^.*[aeiou].*[aeiou].*[aeiou].*$").unwrap();
+    static ref REGEX_P1_2: Regex = Regex::new(r"^.*([a-z])\1.*$").unwrap();
+    static ref REGEX_P1_3: Regex = Regex::new(r"^.*(ab|cd|pq|xy).*$").unwrap();
+    static ref REGEX_P2_1: Regex = Regex::new(r"^.*([a-z])([a-z]).*\1\2.*$").unwrap();
+    static ref REGEX_P2_2: Regex = Regex::new(r"^.*([a-z]).\1.*$").unwrap();
+}
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("ugknbfddgicrmopn", Some("1"), None),
+    Example::new("aaa", Some("1"), None),
+    Example::new("jchzalrnumimnmhp", Some("0"), None),
+    Example::new("haegwjzuvuyypxyu", Some("0"), None),
+    Example::new("dvszwmarrgswjxmb", Some("0"), None),
+    Example::new("qjhvhtzxzqqjkmpb", None, Some("1")),
+    Example::new("xxyxx", None, Some("1")),
+    Example::new("uurcxstgmygtbstg", None, Some("0")),
+    Example::new("ieodomkazucvgmuy", None, Some("0")),
+];
+
+/// Solution for AOC 2015 Day 05.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 05 input file into the format required by the solver functions.
+/// Returned value is vector of strings given as lines in the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<String>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 05 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(raw_input
+        .trim()
+        .lines()
+        .map(|line| line.trim().to_string())
+        .collect::<Vec<String>>())
+}
+
+/// Solves AOC 2015 Day 05 Part 1 // Determines how many of the input strings meet the day05 part1
+/// niceness rules.
+pub fn solve_part1(input_strings: &[String]) -> usize {
+    input_strings
+        .iter()
+        .filter(|s| check_part1_niceness(s))
+        .count()
+}
+
+/// Solves AOC 2015 Day 05 Part 2 // Determines how many of the input strings meet the day05 part2
+/// niceness rules.
+pub fn solve_part2(input_strings: &[String]) -> usize {
+    input_strings
+        .iter()
+        .filter(|s| check_part2_niceness(s))
+        .count()
+}
+
+/// Checks if the candidate string meets the day05 part1 niceness rules.
+fn check_part1_niceness(candidate: &str) -> bool {
+    REGEX_P1_1.is_match(candidate).unwrap()
+        && REGEX_P1_2.is_match(candidate).unwrap()
+        && !REGEX_P1_3.is_match(candidate).unwrap()
+}
+
+/// Checks if the candidate string meets the day05 part2 niceness rules.
+fn check_part2_niceness(candidate: &str) -> bool {
+    REGEX_P2_1.is_match(candidate).unwrap() && REGEX_P2_2.is_match(candidate).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 05 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day05_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(255, solution);
+    }
+
+    /// Tests the Day 05 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day05_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(55, solution);
+    }
+
+    /// Tests the Day 05 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day05_examples() {
+        assert_examples::<Day05>();
+    }
+}
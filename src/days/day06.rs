@@ -1,19 +1,22 @@
 use std::fs;
-use std::time::Instant;
 
 use fancy_regex::Regex;
 use lazy_static::lazy_static;
 
-const PROBLEM_NAME: &str = "Probably a Fire Hazard";
-const PROBLEM_INPUT_FILE: &str = "./input/day06.txt";
-const PROBLEM_DAY: u64 = 6;
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::{parse_capture, ParseError};
+
+pub const PROBLEM_NAME: &str = "Probably a Fire Hazard";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day06.txt";
+pub const PROBLEM_DAY: u64 = 6;
 
 lazy_static! {
     static ref REGEX_LINE: Regex = Regex::new(r"^(.*) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
 }
 
 /// Represents the limits (inclusive) of the light field affected by a particular instruction.
-struct LightField {
+pub struct LightField {
     min_x: usize,
     max_x: usize,
     min_y: usize,
@@ -32,14 +35,14 @@ impl LightField {
 }
 
 /// Represents the different instructions that apply to the light grid.
-enum InstructionType {
+pub enum InstructionType {
     TurnOn,
     TurnOff,
     Toggle,
 }
 
 /// Represents a single instruction that affects a designated area of the lightgrid.
-struct Instruction {
+pub struct Instruction {
     itype: InstructionType,
     field: LightField,
 }
@@ -50,63 +53,72 @@ impl Instruction {
     }
 }
 
-/// Processes the AOC 2015 Day 06 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("turn on 0,0 through 999,999", Some("1000000"), None),
+    Example::new("toggle 0,0 through 999,0", Some("1000"), None),
+    Example::new("turn off 499,499 through 500,500", Some("0"), None),
+    Example::new("turn on 0,0 through 0,0", None, Some("1")),
+    Example::new("toggle 0,0 through 999,999", None, Some("2000000")),
+];
+
+/// Solution for AOC 2015 Day 06.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 06 input file into the format required by the solver functions.
 /// Returned value is vector of instructions specified by the lines of the input file.
-fn process_input_file(filename: &str) -> Vec<Instruction> {
+pub fn process_input_file(filename: &str) -> Result<Vec<Instruction>, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 06 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Instruction>, ParseError> {
     let mut instructions: Vec<Instruction> = vec![];
-    for line in raw_input.lines() {
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
         if let Ok(Some(caps)) = REGEX_LINE.captures(line) {
-            process_regex_captures(caps, &mut instructions);
+            process_regex_captures(caps, i + 1, column, &mut instructions)?;
         } else {
-            panic!("Day06 - bad format input line: {}", line);
+            return Err(ParseError::new(i + 1, column, REGEX_LINE.as_str(), line));
         }
     }
-    instructions
+    Ok(instructions)
 }
 
 /// Solves AOC 2015 Day 06 Part 1 // Determines how many lights are left on in the 1000x1000 light
 /// grid after all instructions have been processed (with all lights starting as off).
-fn solve_part1(instructions: &[Instruction]) -> usize {
+pub fn solve_part1(instructions: &[Instruction]) -> usize {
     // Initialise lightgrid as vec (not slice) to use heap instead of stack (prevent stack OF)
     let mut lightgrid: Vec<Vec<bool>> = vec![vec![false; 1000]; 1000];
     for instruct in instructions {
@@ -139,7 +151,7 @@ fn solve_part1(instructions: &[Instruction]) -> usize {
 
 /// Solves AOC 2015 Day 06 Part 2 // Determines the total brightness of all lights combined after
 /// all instructions have been processed (with all lights starting with brightness 0).
-fn solve_part2(instructions: &[Instruction]) -> u64 {
+pub fn solve_part2(instructions: &[Instruction]) -> u64 {
     // Initialise lightgrid as vec (not slice) to use heap instead of stack (prevent stack OF)
     let mut lightgrid: Vec<Vec<u64>> = vec![vec![0; 1000]; 1000];
     for instruct in instructions {
@@ -172,29 +184,39 @@ fn solve_part2(instructions: &[Instruction]) -> u64 {
 }
 
 /// Processes the regex captures and adds the corresponding Instruction to the output record.
-fn process_regex_captures(caps: fancy_regex::Captures, instructions: &mut Vec<Instruction>) {
-    let min_x = caps[2].parse::<usize>().unwrap();
-    let min_y = caps[3].parse::<usize>().unwrap();
-    let max_x = caps[4].parse::<usize>().unwrap();
-    let max_y = caps[5].parse::<usize>().unwrap();
+fn process_regex_captures(
+    caps: fancy_regex::Captures,
+    line: usize,
+    column: usize,
+    instructions: &mut Vec<Instruction>,
+) -> Result<(), ParseError> {
+    let min_x = parse_capture::<usize>(&caps, 2, line, column, "x-coordinate")?;
+    let min_y = parse_capture::<usize>(&caps, 3, line, column, "y-coordinate")?;
+    let max_x = parse_capture::<usize>(&caps, 4, line, column, "x-coordinate")?;
+    let max_y = parse_capture::<usize>(&caps, 5, line, column, "y-coordinate")?;
     let field = LightField::new(min_x, max_x, min_y, max_y);
     let instruct = match &caps[1] {
         "turn on" => Instruction::new(InstructionType::TurnOn, field),
         "turn off" => Instruction::new(InstructionType::TurnOff, field),
         "toggle" => Instruction::new(InstructionType::Toggle, field),
-        _ => panic!("Day06 - bad instruction: {}", &caps[1]),
+        _ => {
+            let expected = "turn on, turn off or toggle";
+            return Err(ParseError::new(line, column, expected, &caps[1]));
+        }
     };
     instructions.push(instruct);
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 06 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day06_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(377891, solution);
     }
@@ -202,8 +224,14 @@ mod test {
     /// Tests the Day 06 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day06_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(14110788, solution);
     }
+
+    /// Tests the Day 06 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day06_examples() {
+        assert_examples::<Day06>();
+    }
 }
@@ -0,0 +1,212 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::bespoke::{Circuit, CircuitError};
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Some Assembly Required";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day07.txt";
+pub const PROBLEM_DAY: u64 = 7;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "123 -> x\n\
+         456 -> y\n\
+         x AND y -> d\n\
+         x OR y -> e\n\
+         x LSHIFT 2 -> f\n\
+         y RSHIFT 2 -> g\n\
+         NOT x -> h\n\
+         NOT y -> i\n\
+         d -> a\n",
+        Some("72"),
+        None,
+    ),
+    Example::new(
+        "123 -> x\n\
+         456 -> y\n\
+         x AND y -> d\n\
+         x OR y -> e\n\
+         x LSHIFT 2 -> f\n\
+         y RSHIFT 2 -> g\n\
+         NOT x -> h\n\
+         NOT y -> i\n\
+         h -> a\n",
+        Some("65412"),
+        None,
+    ),
+    Example::new(
+        "123 -> x\n\
+         456 -> y\n\
+         x AND y -> d\n\
+         x OR y -> e\n\
+         x LSHIFT 2 -> f\n\
+         y RSHIFT 2 -> g\n\
+         NOT x -> h\n\
+         NOT y -> i\n\
+         i -> a\n",
+        Some("65079"),
+        None,
+    ),
+];
+
+/// Solution for AOC 2015 Day 07.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Circuit;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 07 input file into the format required by the solver functions.
+/// Returned value is the circuit of wires described by the input.
+pub fn process_input_file(filename: &str) -> Result<Circuit, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 07 input into the format required by the solver functions. The
+/// netlist must provide a value to wire "a", without any wire it depends on being unconnected or
+/// part of a feedback loop.
+pub fn parse_input(raw_input: &str) -> Result<Circuit, ParseError> {
+    let circuit = Circuit::new(raw_input)?;
+    match circuit.topological_order(&["a"], &[]) {
+        Ok(_) => Ok(circuit),
+        Err(err) => Err(netlist_error(raw_input, &err)),
+    }
+}
+
+/// Converts a problem preventing wire "a" from being evaluated into a ParseError, located at the
+/// connection to the wire needing the missing wire (or starting the feedback loop). The error has
+/// no location if wire "a" itself has no connection.
+fn netlist_error(raw_input: &str, err: &CircuitError) -> ParseError {
+    let (wire, expected) = match err {
+        CircuitError::MissingWire { wire, required_by } => (
+            required_by.as_deref(),
+            format!("connection providing wire \"{}\"", wire),
+        ),
+        CircuitError::Cycle { path } => (
+            Some(path[0].as_str()),
+            String::from("connections without a feedback loop"),
+        ),
+    };
+    // A later connection to the same wire replaces the earlier one, so the last one is used
+    let location = wire.and_then(|wire| {
+        raw_input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| {
+                line.rsplit_once("->")
+                    .is_some_and(|(_, output)| output.trim() == wire)
+            })
+            .last()
+    });
+    match location {
+        Some((i, line)) => {
+            let column = line.len() - line.trim_start().len() + 1;
+            ParseError::new(i + 1, column, &expected, &err.to_string())
+        }
+        None => ParseError::new(0, 0, &expected, &err.to_string()),
+    }
+}
+
+/// Solves AOC 2015 Day 07 Part 1 // Determines the value that is provided to wire "a".
+pub fn solve_part1(circuit: &Circuit) -> u16 {
+    let mut circuit = circuit.clone();
+    evaluate_wire_a(&mut circuit)
+}
+
+/// Solves AOC 2015 Day 07 Part 2 // Determines the value that is provided to wire "a" after
+/// mapping the initial value of wire "a" to wire "b" and recalculating the wire "a" value.
+pub fn solve_part2(circuit: &Circuit) -> u16 {
+    let mut circuit = circuit.clone();
+    // Calculate initial value of wire "a"
+    let wire_a_value = evaluate_wire_a(&mut circuit);
+    // Update the value provided to wire "b", so only the wires downstream of it are recalculated
+    circuit.force("b", wire_a_value);
+    evaluate_wire_a(&mut circuit)
+}
+
+/// Determines the value provided to wire "a", panicking if it cannot be determined (which
+/// [`parse_input`] rules out).
+fn evaluate_wire_a(circuit: &mut Circuit) -> u16 {
+    match circuit.value("a") {
+        Ok(value) => value,
+        Err(err) => panic!("Unable to evaluate wire \"a\": {}", err),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 07 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day07_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(956, solution);
+    }
+
+    /// Tests the Day 07 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day07_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(40149, solution);
+    }
+
+    /// Tests that a bad format input line is reported with its line and column numbers.
+    #[test]
+    fn test_day07_parse_error_location() {
+        let err = parse_input("123 -> x\n  x XOR y -> z\n").err().unwrap();
+        assert_eq!((2, 3), (err.line(), err.column()));
+        assert_eq!("x XOR y -> z", err.found());
+    }
+
+    /// Tests that a netlist unable to provide a value to wire "a" is rejected when it is parsed.
+    #[test]
+    fn test_day07_netlist_errors() {
+        let err = parse_input("123 -> b\n").unwrap_err();
+        assert_eq!(0, err.line());
+        assert_eq!("connection providing wire \"a\"", err.expected());
+        let err = parse_input("123 -> b\n  b AND y -> a\n").unwrap_err();
+        assert_eq!((2, 3), (err.line(), err.column()));
+        assert_eq!("connection providing wire \"y\"", err.expected());
+        assert_eq!("wire \"y\" (required by \"a\") has no input", err.found());
+        let err = parse_input("NOT b -> a\n1 -> x\nx OR a -> b\n").unwrap_err();
+        assert_eq!((1, 1), (err.line(), err.column()));
+        assert_eq!("connections without a feedback loop", err.expected());
+        assert_eq!("feedback loop between wires a -> b -> a", err.found());
+    }
+
+    /// Tests the Day 07 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day07_examples() {
+        assert_examples::<Day07>();
+    }
+}
@@ -0,0 +1,210 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::{Param, Params, Solution};
+use crate::utils::bespoke::{EscapeDialect, StringCodec};
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Matchsticks";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day08.txt";
+pub const PROBLEM_DAY: u64 = 8;
+
+/// Escape dialects that can be selected for the string literals given in the input, in the order of
+/// their values for the dialect parameter.
+const DIALECTS: [EscapeDialect; 3] = [
+    EscapeDialect::Aoc2015,
+    EscapeDialect::Rust,
+    EscapeDialect::Json,
+];
+
+const DIALECT: Param = Param {
+    name: "dialect",
+    default: 0,
+    description: "Escape dialect of the string literals (aoc2015, rust or json)",
+    parse: Some(parse_dialect),
+};
+
+/// Parses the name of an escape dialect into its value for the dialect parameter.
+fn parse_dialect(name: &str) -> Result<u64, String> {
+    let dialect: EscapeDialect = name.parse()?;
+    Ok(DIALECTS.iter().position(|other| *other == dialect).unwrap() as u64)
+}
+
+/// Returns the escape dialect selected by the given solver parameter values.
+fn dialect(params: &Params) -> EscapeDialect {
+    DIALECTS[params.get(&DIALECT) as usize]
+}
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "\"\"\n\
+     \"abc\"\n\
+     \"aaa\\\"aaa\"\n\
+     \"\\x27\"\n",
+    Some("12"),
+    Some("19"),
+)];
+
+/// Solution for AOC 2015 Day 08.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMS: &'static [Param] = &[DIALECT];
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn parse_with(raw_input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        parse_input_with_dialect(raw_input, dialect(params))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Part1 {
+        solve_part1_with_dialect(input, dialect(params))
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Part2 {
+        solve_part2_with_dialect(input, dialect(params))
+    }
+}
+
+/// Processes the AOC 2015 Day 08 input file into the format required by the solver functions.
+/// Returned value is a vector of strings given as lines in the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<String>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 08 input into the format required by the solver functions.
+/// Each line must be a well-formed string literal, using only the escapes from the problem.
+pub fn parse_input(raw_input: &str) -> Result<Vec<String>, ParseError> {
+    parse_input_with_dialect(raw_input, EscapeDialect::Aoc2015)
+}
+
+/// Parses the raw AOC 2015 Day 08 input, with each line being a well-formed string literal using
+/// the escapes of the given dialect.
+pub fn parse_input_with_dialect(
+    raw_input: &str,
+    dialect: EscapeDialect,
+) -> Result<Vec<String>, ParseError> {
+    let codec = StringCodec::new(dialect);
+    let mut input_strings: Vec<String> = vec![];
+    for (i, line) in raw_input.trim().lines().enumerate() {
+        if let Err(errors) = codec.decode(line) {
+            let err = &errors[0];
+            let column = line[..err.position()].chars().count() + 1;
+            return Err(ParseError::new(i + 1, column, err.expected(), err.found()));
+        }
+        input_strings.push(line.to_string());
+    }
+    Ok(input_strings)
+}
+
+/// Solves AOC 2015 Day 08 Part 1 // Determines the difference between the total number of
+/// characters in the "in-code" and "in-memory" representations of the input strings.
+pub fn solve_part1(input_strings: &[String]) -> usize {
+    solve_part1_with_dialect(input_strings, EscapeDialect::Aoc2015)
+}
+
+/// Determines the difference between the total number of characters in the "in-code" and
+/// "in-memory" representations of the input strings, decoded using the given escape dialect.
+pub fn solve_part1_with_dialect(input_strings: &[String], dialect: EscapeDialect) -> usize {
+    let codec = StringCodec::new(dialect);
+    let mut chars_code = 0;
+    let mut chars_mem = 0;
+    for s in input_strings {
+        // Add to in-code and in-mem length totals
+        let lengths = codec.lengths(s).unwrap();
+        chars_code += lengths.code_bytes;
+        chars_mem += lengths.decoded_bytes;
+    }
+    chars_code - chars_mem
+}
+
+/// Solves AOC 2015 Day 08 Part 2 // Determines the difference between the total number of
+/// characters in the new-encoding and in-code representations of the input strings.
+pub fn solve_part2(input_strings: &[String]) -> usize {
+    solve_part2_with_dialect(input_strings, EscapeDialect::Aoc2015)
+}
+
+/// Determines the difference between the total number of characters in the new-encoding and
+/// in-code representations of the input strings, encoded using the given escape dialect.
+pub fn solve_part2_with_dialect(input_strings: &[String], dialect: EscapeDialect) -> usize {
+    let codec = StringCodec::new(dialect);
+    let mut chars_encoded = 0;
+    let mut chars_code = 0;
+    for s in input_strings {
+        // Add to new-encoding and in-code length totals
+        chars_code += s.len();
+        chars_encoded += codec.encode(s.as_bytes()).len();
+    }
+    chars_encoded - chars_code
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 08 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day08_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(1371, solution);
+    }
+
+    /// Tests the Day 08 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day08_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(2117, solution);
+    }
+
+    /// Tests that a malformed escape is reported with its line and column numbers.
+    #[test]
+    fn test_day08_parse_error_location() {
+        let err = parse_input("\"abc\"\n\"a\\x4g\"\n").err().unwrap();
+        assert_eq!((2, 3), (err.line(), err.column()));
+        assert_eq!("\\x4", err.found());
+    }
+
+    /// Tests that the escape dialect of the string literals is selected by the dialect parameter.
+    #[test]
+    fn test_day08_dialect_param() {
+        let raw_input = "\"tab\\t\"\n\"\\u{e9}\"\n";
+        assert!(parse_input(raw_input).is_err());
+        let params = Params::from_pairs(&[("dialect", DIALECT.parse_value("rust").unwrap())]);
+        let input = Day08::parse_with(raw_input, &params).unwrap();
+        assert_eq!(9, Day08::part1_with(&input, &params));
+        assert_eq!(10, Day08::part2_with(&input, &params));
+        let err = DIALECT.parse_value("c").unwrap_err();
+        assert_eq!("unknown escape dialect: \"c\"", err);
+    }
+
+    /// Tests the Day 08 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day08_examples() {
+        assert_examples::<Day08>();
+    }
+}
@@ -0,0 +1,1586 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use fancy_regex::Regex;
+use itertools::Itertools;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::{parse_capture, ParseError, Prng};
+
+pub const PROBLEM_NAME: &str = "All in a Single Night";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day09.txt";
+pub const PROBLEM_DAY: u64 = 9;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "London to Dublin = 464\n\
+     London to Belfast = 518\n\
+     Dublin to Belfast = 141\n",
+    Some("605"),
+    Some("982"),
+)];
+
+/// Solution for AOC 2015 Day 09.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = HashMap<String, HashMap<String, u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 09 input file into the format required by the solver functions.
+/// Returned value is hashmap mapping edge "from" nodes to edge "to" nodes and the associated edge
+/// weight.
+pub fn process_input_file(
+    filename: &str,
+) -> Result<HashMap<String, HashMap<String, u64>>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 09 input into the format required by the solver functions. Each
+/// distance applies in both directions between the nodes.
+pub fn parse_input(raw_input: &str) -> Result<HashMap<String, HashMap<String, u64>>, ParseError> {
+    parse_input_with_mode(raw_input, GraphMode::Symmetric)
+}
+
+/// Parses the raw AOC 2015 Day 09 input, with each distance applying in one or both directions
+/// between the nodes depending on the graph mode. Returns an error for a line giving the distance
+/// from a node to itself or a different distance for a pair of nodes already given, or for pairs
+/// of nodes without a distance once the whole input has been read. The error for missing distances
+/// lists every missing pair, and is located where the later of the nodes in the first pair first
+/// appears in the input.
+pub fn parse_input_with_mode(
+    raw_input: &str,
+    mode: GraphMode,
+) -> Result<HashMap<String, HashMap<String, u64>>, ParseError> {
+    let mut edges: HashMap<String, HashMap<String, u64>> = HashMap::new();
+    let mut first_seen: HashMap<String, (usize, usize)> = HashMap::new(); // line and column
+    let regex_line = Regex::new(r"^([[:alpha:]]+) to ([[:alpha:]]+) = (\d+)$").unwrap();
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Ok(Some(caps)) = regex_line.captures(line) {
+            for group in [1, 2] {
+                let node = caps.get(group).unwrap();
+                first_seen
+                    .entry(node.as_str().to_string())
+                    .or_insert((i + 1, column + node.start()));
+            }
+            let distance = parse_capture::<u64>(&caps, 3, i + 1, column, "distance")?;
+            insert_distance(&mut edges, &caps[1], &caps[2], distance, mode)
+                .map_err(|err| ParseError::new(i + 1, column, &err.expected(), line))?;
+        } else {
+            return Err(ParseError::new(i + 1, column, regex_line.as_str(), line));
+        }
+    }
+    // Each missing distance is missing in both directions in symmetric mode, so only list it once
+    let missing: Vec<(String, String)> = validate_graph(&edges)
+        .into_iter()
+        .filter_map(|err| match err {
+            GraphError::MissingDistance { from, to } => Some((from, to)),
+            _ => None,
+        })
+        .filter(|(from, to)| mode == GraphMode::Asymmetric || from < to)
+        .collect();
+    if let Some((from, to)) = missing.first() {
+        let (line, column) = first_seen[from].max(first_seen[to]);
+        let pairs = missing
+            .iter()
+            .map(|(from, to)| match mode {
+                GraphMode::Symmetric => format!("between {} and {}", from, to),
+                GraphMode::Asymmetric => format!("from {} to {}", from, to),
+            })
+            .join(", ");
+        let plural = if missing.len() > 1 { "s" } else { "" };
+        let expected = format!("distance{} {}", plural, pairs);
+        return Err(ParseError::new(line, column, &expected, "end of input"));
+    }
+    Ok(edges)
+}
+
+/// Represents whether each distance given in the input applies in both directions between the
+/// nodes, or only from the first node to the second.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphMode {
+    #[default]
+    Symmetric,
+    Asymmetric,
+}
+
+/// Represents a problem with the distances given between the nodes of a graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GraphError {
+    /// A distance was given from the node to itself.
+    SelfLoop { node: String },
+    /// A distance was given from one node to another after a different distance had already been
+    /// given.
+    ConflictingDistance {
+        from: String,
+        to: String,
+        first: u64,
+        second: u64,
+    },
+    /// No distance was given from one node to another.
+    MissingDistance { from: String, to: String },
+}
+
+impl GraphError {
+    /// Returns a description of what the graph needed to have instead of the problem.
+    pub fn expected(&self) -> String {
+        match self {
+            GraphError::SelfLoop { .. } => String::from("distance between two different nodes"),
+            GraphError::ConflictingDistance {
+                from, to, first, ..
+            } => format!("distance {} from {} to {} given earlier", first, from, to),
+            GraphError::MissingDistance { from, to } => {
+                format!("distance from {} to {}", from, to)
+            }
+        }
+    }
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::SelfLoop { node } => write!(f, "distance from \"{}\" to itself", node),
+            GraphError::ConflictingDistance {
+                from,
+                to,
+                first,
+                second,
+            } => write!(
+                f,
+                "conflicting distances {} and {} from \"{}\" to \"{}\"",
+                first, second, from, to
+            ),
+            GraphError::MissingDistance { from, to } => {
+                write!(f, "no distance from \"{}\" to \"{}\"", from, to)
+            }
+        }
+    }
+}
+
+impl Error for GraphError {}
+
+/// Adds the distance from node1 to node2 to the graph, and also from node2 to node1 in symmetric
+/// mode. Returns an error if the nodes are the same or a different distance has already been given
+/// between them, in which case the graph is left unchanged.
+pub fn insert_distance(
+    edges: &mut HashMap<String, HashMap<String, u64>>,
+    node1: &str,
+    node2: &str,
+    distance: u64,
+    mode: GraphMode,
+) -> Result<(), GraphError> {
+    if node1 == node2 {
+        return Err(GraphError::SelfLoop {
+            node: node1.to_string(),
+        });
+    }
+    let mut directions = vec![(node1, node2)];
+    if mode == GraphMode::Symmetric {
+        directions.push((node2, node1));
+    }
+    for &(from, to) in directions.iter() {
+        if let Some(&first) = edges.get(from).and_then(|to_nodes| to_nodes.get(to)) {
+            if first != distance {
+                return Err(GraphError::ConflictingDistance {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    first,
+                    second: distance,
+                });
+            }
+        }
+    }
+    for (from, to) in directions {
+        add_edges_between_nodes(edges, from, to, distance);
+    }
+    // Nodes only reached by one-way distances are still included in the graph
+    edges.entry(node2.to_string()).or_default();
+    Ok(())
+}
+
+/// Checks that a distance is given from each node of the graph to every other node and never from
+/// a node to itself. Returned value is the list of problems found, ordered by node names, which is
+/// empty if the graph is valid.
+pub fn validate_graph(edges: &HashMap<String, HashMap<String, u64>>) -> Vec<GraphError> {
+    let nodes = graph_nodes(edges);
+    let mut errors: Vec<GraphError> = vec![];
+    for from in nodes.iter() {
+        for to in nodes.iter() {
+            let distance = edges.get(*from).and_then(|to_nodes| to_nodes.get(*to));
+            if from == to && distance.is_some() {
+                errors.push(GraphError::SelfLoop {
+                    node: from.to_string(),
+                });
+            } else if from != to && distance.is_none() {
+                errors.push(GraphError::MissingDistance {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// Adds a new edge going from node1 to node2 with the given weight.
+fn add_edges_between_nodes(
+    edges: &mut HashMap<String, HashMap<String, u64>>,
+    node1: &str,
+    node2: &str,
+    weight: u64,
+) {
+    if let Entry::Vacant(e) = edges.entry(node1.to_string()) {
+        e.insert(HashMap::from([(node2.to_string(), weight)]));
+    } else {
+        edges
+            .get_mut(&node1.to_string())
+            .unwrap()
+            .insert(node2.to_string(), weight);
+    }
+}
+
+/// Returns the names of the nodes in the graph in name order, including nodes that are only the
+/// destination of distances from other nodes.
+fn graph_nodes(edges: &HashMap<String, HashMap<String, u64>>) -> Vec<&String> {
+    edges
+        .keys()
+        .chain(edges.values().flat_map(|to_nodes| to_nodes.keys()))
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// Solves AOC 2015 Day 09 Part 1 // Determines the minimum distance required to visit all nodes in
+/// the graph.
+/// Graphs too large for the Held-Karp solver are solved with the 2-opt heuristic instead, which may
+/// not find the shortest route.
+pub fn solve_part1(edges: &HashMap<String, HashMap<String, u64>>) -> u64 {
+    match find_route_distance(edges, Objective::Shortest) {
+        Ok(min_dist) => min_dist,
+        Err(RouteError::TooManyNodes { .. } | RouteError::DistanceTooLarge { .. }) => {
+            find_two_opt_route(edges, Objective::Shortest, &HeuristicOptions::default())
+                .expect("Did not find the minimum distance path!")
+                .route
+                .distance
+        }
+        Err(err) => panic!("Did not find the minimum distance path: {}", err),
+    }
+}
+
+/// Solves AOC 2015 Day 09 Part 2 // Determines the maximum distance required to visit all nodes in
+/// the graph.
+/// Graphs too large for the Held-Karp solver are solved with the 2-opt heuristic instead, which may
+/// not find the longest route.
+pub fn solve_part2(edges: &HashMap<String, HashMap<String, u64>>) -> u64 {
+    match find_route_distance(edges, Objective::Longest) {
+        Ok(max_dist) => max_dist,
+        Err(RouteError::TooManyNodes { .. } | RouteError::DistanceTooLarge { .. }) => {
+            find_two_opt_route(edges, Objective::Longest, &HeuristicOptions::default())
+                .expect("Did not find the maximum distance path!")
+                .route
+                .distance
+        }
+        Err(err) => panic!("Did not find the maximum distance path: {}", err),
+    }
+}
+
+/// Represents whether the shortest or longest route is being searched for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Objective {
+    Shortest,
+    Longest,
+}
+
+impl Objective {
+    /// Checks if the candidate distance is better than the current best distance.
+    fn is_better<T: PartialOrd>(&self, candidate: T, current: T) -> bool {
+        match self {
+            Objective::Shortest => candidate < current,
+            Objective::Longest => candidate > current,
+        }
+    }
+
+    /// Returns how much the change in the distance of a route improves the route, being negative
+    /// if the change makes the route worse.
+    fn gain(&self, change: i64) -> i64 {
+        match self {
+            Objective::Shortest => -change,
+            Objective::Longest => change,
+        }
+    }
+}
+
+/// Represents a route visiting nodes of the graph in order, along with the distance of each leg
+/// between consecutive nodes and the total distance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub nodes: Vec<String>,
+    pub legs: Vec<u64>,
+    pub distance: u64,
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -({})-> ", self.legs[i - 1])?;
+            }
+            write!(f, "{}", node)?;
+        }
+        write!(f, " = {}", self.distance)
+    }
+}
+
+/// Constraints on the routes considered by the route solvers. By default, routes may start and end
+/// at any node and use the edge between any pair of nodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteConstraints {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub round_trip: bool,
+    pub forbidden_legs: Vec<(String, String)>,
+}
+
+impl RouteConstraints {
+    /// Returns the constraints with routes required to start at the given node.
+    pub fn with_start(mut self, node: &str) -> RouteConstraints {
+        self.start = Some(node.to_string());
+        self
+    }
+
+    /// Returns the constraints with routes required to end at the given node.
+    pub fn with_end(mut self, node: &str) -> RouteConstraints {
+        self.end = Some(node.to_string());
+        self
+    }
+
+    /// Returns the constraints with routes required to return to their start node after visiting
+    /// every node. Round trips start from the node with the earliest name, unless the start (or
+    /// end) node is pinned.
+    pub fn with_round_trip(mut self) -> RouteConstraints {
+        self.round_trip = true;
+        self
+    }
+
+    /// Returns the constraints with the leg between the given nodes forbidden in both directions.
+    pub fn with_forbidden_leg(mut self, node1: &str, node2: &str) -> RouteConstraints {
+        self.forbidden_legs
+            .push((node1.to_string(), node2.to_string()));
+        self
+    }
+}
+
+/// Represents a reason the exact route solvers could not return a route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RouteError {
+    /// The graph has more nodes than the solver's tables can hold.
+    TooManyNodes { nodes: usize, limit: usize },
+    /// A route through the graph could be longer than the solver's tables can hold.
+    DistanceTooLarge { bound: u64, limit: u64 },
+    /// A pinned start or end node is not in the graph.
+    UnknownNode { node: String },
+    /// A round trip was required with different start and end nodes.
+    ConflictingEnds { start: String, end: String },
+    /// No route visits every node while meeting the constraints.
+    NoRoute,
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::TooManyNodes { nodes, limit } => write!(
+                f,
+                "graph has {} nodes, more than the limit of {} for the exact route solver",
+                nodes, limit
+            ),
+            RouteError::DistanceTooLarge { bound, limit } => write!(
+                f,
+                "routes could be {} long, more than the limit of {} for the exact route solver",
+                bound, limit
+            ),
+            RouteError::UnknownNode { node } => write!(f, "node \"{}\" is not in the graph", node),
+            RouteError::ConflictingEnds { start, end } => write!(
+                f,
+                "round trip cannot start at \"{}\" and end at \"{}\"",
+                start, end
+            ),
+            RouteError::NoRoute => {
+                write!(f, "no route visits every node and meets the constraints")
+            }
+        }
+    }
+}
+
+impl Error for RouteError {}
+
+/// Largest number of nodes in a graph searched by the Held-Karp solvers, whose tables grow with
+/// n * 2^(n-1) entries for n nodes (about 185 MB for the best route at this limit). Larger graphs
+/// need the heuristic solvers ([`find_nearest_neighbour_route`], [`find_two_opt_route`] and
+/// [`find_annealed_route`]).
+pub const EXACT_ROUTE_NODE_LIMIT: usize = 22;
+
+/// Placeholder for the distance of a route that has not been reached by the Held-Karp solver. The
+/// tables hold distances as u32 to halve their size, so every route must be shorter than this.
+const UNREACHED: u32 = u32::MAX;
+
+/// Returns the number of states in the Held-Karp tables for a graph with n nodes (at least one),
+/// being each subset of nodes visited paired with the node in it where the route ends.
+fn state_count(n: usize) -> usize {
+    n << (n - 1)
+}
+
+/// Returns the position in the Held-Karp tables of the state for routes visiting the nodes in the
+/// subset (bitmask) and ending at the given node. The end node's bit is always set, so it is left
+/// out of the subset to halve the size of the tables.
+fn state_index(n: usize, visited: usize, last: usize) -> usize {
+    let others = (visited & ((1 << last) - 1)) | ((visited >> (last + 1)) << last);
+    others * n + last
+}
+
+/// Distances between each pair of nodes in the graph, with the nodes indexed in name order. The
+/// distance is None if there is no edge between the nodes.
+struct DistanceMatrix {
+    nodes: Vec<String>,
+    distances: Vec<Vec<Option<u64>>>,
+}
+
+impl DistanceMatrix {
+    fn new(edges: &HashMap<String, HashMap<String, u64>>) -> DistanceMatrix {
+        let nodes: Vec<String> = graph_nodes(edges).into_iter().cloned().collect();
+        let distances = nodes
+            .iter()
+            .map(|from| {
+                nodes
+                    .iter()
+                    .map(|to| {
+                        edges
+                            .get(from)
+                            .and_then(|to_nodes| to_nodes.get(to))
+                            .copied()
+                    })
+                    .collect::<Vec<Option<u64>>>()
+            })
+            .collect();
+        DistanceMatrix { nodes, distances }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the index of the node with the given name, if it is in the graph.
+    fn index(&self, node: &str) -> Option<usize> {
+        self.nodes
+            .binary_search_by(|other| other.as_str().cmp(node))
+            .ok()
+    }
+
+    /// Checks if the distance between each pair of nodes is the same in both directions.
+    fn is_symmetric(&self) -> bool {
+        (0..self.len()).all(|i| (0..i).all(|j| self.distances[i][j] == self.distances[j][i]))
+    }
+}
+
+/// The graph distances and route constraints used by the route solvers, with the forbidden legs
+/// removed from the graph and the pinned nodes given by index.
+struct RoutePlan {
+    matrix: DistanceMatrix,
+    start: Option<usize>,
+    end: Option<usize>,
+    round_trip: bool,
+    is_symmetric: bool,
+}
+
+impl RoutePlan {
+    /// Applies the constraints to the graph. Returns an error if a pinned node is not in the graph,
+    /// or a round trip is required with different start and end nodes.
+    fn new(
+        edges: &HashMap<String, HashMap<String, u64>>,
+        constraints: &RouteConstraints,
+    ) -> Result<RoutePlan, RouteError> {
+        let mut matrix = DistanceMatrix::new(edges);
+        let index = |node: &String| {
+            matrix
+                .index(node)
+                .ok_or_else(|| RouteError::UnknownNode { node: node.clone() })
+        };
+        let mut start = constraints.start.as_ref().map(index).transpose()?;
+        let mut end = constraints.end.as_ref().map(index).transpose()?;
+        for (node1, node2) in constraints.forbidden_legs.iter() {
+            if let (Some(i), Some(j)) = (matrix.index(node1), matrix.index(node2)) {
+                matrix.distances[i][j] = None;
+                matrix.distances[j][i] = None;
+            }
+        }
+        if constraints.round_trip {
+            // Round trips end where they start, so the end node is handled by the closing leg
+            start = match (start, end) {
+                (Some(s), Some(e)) if s != e => {
+                    return Err(RouteError::ConflictingEnds {
+                        start: matrix.nodes[s].clone(),
+                        end: matrix.nodes[e].clone(),
+                    });
+                }
+                (Some(node), _) | (None, Some(node)) => Some(node),
+                (None, None) => (matrix.len() > 0).then_some(0),
+            };
+            end = None;
+        }
+        let is_symmetric = matrix.is_symmetric();
+        Ok(RoutePlan {
+            matrix,
+            start,
+            end,
+            round_trip: constraints.round_trip,
+            is_symmetric,
+        })
+    }
+
+    /// Checks that the Held-Karp tables can hold the graph, having at most the given number of
+    /// nodes and no route too long to be held as a u32 distance.
+    fn check_table_limits(&self, node_limit: usize) -> Result<(), RouteError> {
+        let n = self.matrix.len();
+        if n > node_limit {
+            return Err(RouteError::TooManyNodes {
+                nodes: n,
+                limit: node_limit,
+            });
+        }
+        // Each route leaves each node at most once, so no route is longer than the sum of the
+        // longest leg from each node
+        let bound = self
+            .matrix
+            .distances
+            .iter()
+            .map(|legs| legs.iter().flatten().copied().max().unwrap_or(0))
+            .fold(0u64, |bound, leg| bound.saturating_add(leg));
+        let limit = UNREACHED as u64 - 1;
+        if bound > limit {
+            return Err(RouteError::DistanceTooLarge { bound, limit });
+        }
+        Ok(())
+    }
+
+    /// Returns the distance of the leg between the given nodes as held in the Held-Karp tables,
+    /// which [`RoutePlan::check_table_limits`] ensures cannot overflow.
+    fn table_leg(&self, from: usize, to: usize) -> Option<u32> {
+        self.matrix.distances[from][to].map(|leg| leg as u32)
+    }
+
+    /// Checks if routes can start at the given node.
+    fn can_start_at(&self, node: usize) -> bool {
+        self.start.is_none_or(|start| start == node)
+    }
+
+    /// Returns the distance needed to complete a route that has visited every node and is at the
+    /// given node, being the leg back to the start node for a round trip. Returned value is None if
+    /// the route cannot be completed from the node.
+    fn closing_distance(&self, last: usize, first: usize) -> Option<u64> {
+        if self.round_trip && self.matrix.len() > 1 {
+            self.matrix.distances[last][first]
+        } else if self.end.is_none_or(|end| end == last) {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if each route would otherwise be found in both directions, which happens for a
+    /// symmetric graph unless the route has a pinned start or end node.
+    fn has_reversed_duplicates(&self) -> bool {
+        self.is_symmetric && (self.round_trip || (self.start.is_none() && self.end.is_none()))
+    }
+
+    /// Creates the route visiting the nodes with the given indices in order, returning to the
+    /// start node for a round trip. Routes that could be reversed are given in the direction where
+    /// the end node (or for a round trip, the second node) with the earlier name comes first.
+    fn route(&self, order: &[usize]) -> Route {
+        let mut order = order.to_vec();
+        if self.has_reversed_duplicates() {
+            if self.round_trip && order.len() > 2 && order[1] > order[order.len() - 1] {
+                order[1..].reverse();
+            } else if !self.round_trip && order.first() > order.last() {
+                order.reverse();
+            }
+        }
+        if self.round_trip && order.len() > 1 {
+            order.push(order[0]);
+        }
+        let legs: Vec<u64> = order
+            .windows(2)
+            .map(|pair| self.matrix.distances[pair[0]][pair[1]].unwrap())
+            .collect();
+        Route {
+            nodes: order
+                .iter()
+                .map(|&i| self.matrix.nodes[i].to_string())
+                .collect(),
+            distance: legs.iter().sum(),
+            legs,
+        }
+    }
+}
+
+/// Finds the distance of the best route (shortest or longest) visiting each node in the graph
+/// exactly once. Returns an error if no route visits all of the nodes, or the graph is too large
+/// for [`find_best_route`].
+pub fn find_route_distance(
+    edges: &HashMap<String, HashMap<String, u64>>,
+    objective: Objective,
+) -> Result<u64, RouteError> {
+    find_best_route(edges, objective, &RouteConstraints::default()).map(|route| route.distance)
+}
+
+/// Finds the best route (shortest or longest) visiting each node in the graph exactly once and
+/// meeting the given constraints, using the Held-Karp dynamic programming algorithm. This takes
+/// O(n^2 * 2^n) time and O(n * 2^n) space for n nodes. Returns an error if no route meets the
+/// constraints, or the graph has more than [`EXACT_ROUTE_NODE_LIMIT`] nodes or routes too long for
+/// the solver's tables.
+pub fn find_best_route(
+    edges: &HashMap<String, HashMap<String, u64>>,
+    objective: Objective,
+    constraints: &RouteConstraints,
+) -> Result<Route, RouteError> {
+    let plan = RoutePlan::new(edges, constraints)?;
+    let n = plan.matrix.len();
+    if n == 0 {
+        return Ok(plan.route(&[]));
+    }
+    plan.check_table_limits(EXACT_ROUTE_NODE_LIMIT)?;
+    // Best distance of a route visiting the nodes in the subset (bitmask) and ending at each node
+    let full = (1usize << n) - 1;
+    let mut best: Vec<u32> = vec![UNREACHED; state_count(n)];
+    for node in (0..n).filter(|&node| plan.can_start_at(node)) {
+        best[state_index(n, 1 << node, node)] = 0;
+    }
+    for visited in 1..=full {
+        for last in (0..n).filter(|last| visited & (1 << last) != 0) {
+            let dist = best[state_index(n, visited, last)];
+            if dist == UNREACHED {
+                continue;
+            }
+            for next in (0..n).filter(|next| visited & (1 << next) == 0) {
+                let Some(leg) = plan.table_leg(last, next) else {
+                    continue;
+                };
+                let state = &mut best[state_index(n, visited | (1 << next), next)];
+                if *state == UNREACHED || objective.is_better(dist + leg, *state) {
+                    *state = dist + leg;
+                }
+            }
+        }
+    }
+    let first = plan.start.unwrap_or(0);
+    let total = |last: usize| match best[state_index(n, full, last)] {
+        UNREACHED => None,
+        dist => plan
+            .closing_distance(last, first)
+            .map(|leg| dist as u64 + leg),
+    };
+    let mut last = (0..n)
+        .filter(|&last| total(last).is_some())
+        .reduce(|a, b| {
+            if objective.is_better(total(b).unwrap(), total(a).unwrap()) {
+                b
+            } else {
+                a
+            }
+        })
+        .ok_or(RouteError::NoRoute)?;
+    // Walk back through the nodes visited, finding the node each best route was extended from
+    let mut order: Vec<usize> = vec![last];
+    let mut visited = full;
+    while visited != 1 << last {
+        let previous_visited = visited ^ (1 << last);
+        let previous = (0..n)
+            .filter(|node| previous_visited & (1 << node) != 0)
+            .find(|&node| {
+                let dist = best[state_index(n, previous_visited, node)];
+                dist != UNREACHED
+                    && plan.table_leg(node, last).map(|leg| dist + leg)
+                        == Some(best[state_index(n, visited, last)])
+            })
+            .unwrap();
+        order.push(previous);
+        visited = previous_visited;
+        last = previous;
+    }
+    order.reverse();
+    Ok(plan.route(&order))
+}
+
+/// Largest number of partial routes held by [`find_k_best_routes`] across all of its states (about
+/// 34 MB), which sets the largest graph it searches for a given k.
+pub const K_BEST_ROUTE_ENTRY_LIMIT: usize = 1 << 22;
+
+/// Returns the largest number of nodes in a graph searched by [`find_k_best_routes`] when keeping
+/// the given number of partial routes for each state.
+fn k_best_node_limit(capacity: usize) -> usize {
+    (1..=EXACT_ROUTE_NODE_LIMIT)
+        .take_while(|&n| capacity.saturating_mul(state_count(n)) <= K_BEST_ROUTE_ENTRY_LIMIT)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Finds the k best routes (shortest or longest) visiting each node in the graph exactly once and
+/// meeting the given constraints, ordered from best to worst. For a symmetric graph a route and its
+/// reverse are the same route, so only one of the two is included. Uses the Held-Karp algorithm
+/// keeping the k best partial routes for each state, taking O(k * n^2 * 2^n) time and
+/// O(k * n * 2^n) space for n nodes. Returns an error if no route meets the constraints, or the
+/// graph has too many nodes to keep k partial routes for each state within
+/// [`K_BEST_ROUTE_ENTRY_LIMIT`].
+pub fn find_k_best_routes(
+    edges: &HashMap<String, HashMap<String, u64>>,
+    objective: Objective,
+    constraints: &RouteConstraints,
+    k: usize,
+) -> Result<Vec<Route>, RouteError> {
+    let plan = RoutePlan::new(edges, constraints)?;
+    let n = plan.matrix.len();
+    if k == 0 {
+        return Ok(vec![]);
+    } else if n == 0 {
+        return Ok(vec![plan.route(&[])]);
+    }
+    // Reversed round trips share their partial routes' states, so keep enough partial routes for
+    // each state that k distinct round trips remain once the duplicates are removed
+    let capacity = match plan.round_trip && plan.has_reversed_duplicates() {
+        true => k.saturating_mul(2),
+        false => k,
+    };
+    plan.check_table_limits(k_best_node_limit(capacity))?;
+    // Best partial routes for each state (best first) in consecutive slots of one table, held as
+    // the distance along with the previous node and the rank of the partial route it was extended
+    // from (packed as rank * n + node). Unused slots are left at the end of each state's slots.
+    let full = (1usize << n) - 1;
+    let mut ranked: Vec<(u32, u32)> = vec![(UNREACHED, 0); state_count(n) * capacity];
+    let slots = |visited: usize, last: usize| state_index(n, visited, last) * capacity;
+    for node in (0..n).filter(|&node| plan.can_start_at(node)) {
+        ranked[slots(1 << node, node)] = (0, node as u32);
+    }
+    for visited in 1..=full {
+        for last in (0..n).filter(|last| visited & (1 << last) != 0) {
+            let state = slots(visited, last);
+            for rank in 0..capacity {
+                let dist = ranked[state + rank].0;
+                if dist == UNREACHED {
+                    break;
+                }
+                for next in (0..n).filter(|next| visited & (1 << next) == 0) {
+                    let Some(leg) = plan.table_leg(last, next) else {
+                        continue;
+                    };
+                    let next_state = slots(visited | (1 << next), next);
+                    let next_slots = &mut ranked[next_state..next_state + capacity];
+                    let Some(position) = next_slots.iter().position(|&(other, _)| {
+                        other == UNREACHED || objective.is_better(dist + leg, other)
+                    }) else {
+                        continue;
+                    };
+                    next_slots.copy_within(position..capacity - 1, position + 1);
+                    next_slots[position] = (dist + leg, (rank * n + last) as u32);
+                }
+            }
+        }
+    }
+    // Reconstruct the complete routes, keeping one direction of each route in a symmetric graph
+    let mut routes: Vec<Route> = vec![];
+    for end in 0..n {
+        for rank in 0..capacity {
+            if ranked[slots(full, end) + rank].0 == UNREACHED {
+                break;
+            }
+            let mut order: Vec<usize> = vec![end];
+            let (mut visited, mut last, mut rank) = (full, end, rank);
+            while visited != 1 << last {
+                let from = ranked[slots(visited, last) + rank].1 as usize;
+                order.push(from % n);
+                visited ^= 1 << last;
+                (last, rank) = (from % n, from / n);
+            }
+            order.reverse();
+            if plan.closing_distance(end, order[0]).is_some() {
+                routes.push(plan.route(&order));
+            }
+        }
+    }
+    if routes.is_empty() {
+        return Err(RouteError::NoRoute);
+    }
+    routes.sort_by(|a, b| match objective {
+        Objective::Shortest => a.distance.cmp(&b.distance),
+        Objective::Longest => b.distance.cmp(&a.distance),
+    });
+    if plan.has_reversed_duplicates() {
+        let mut seen: HashSet<Vec<String>> = HashSet::new();
+        routes.retain(|route| seen.insert(route.nodes.clone()));
+    }
+    routes.truncate(k);
+    Ok(routes)
+}
+
+/// Options for the heuristic route solvers, limiting how long the search runs and seeding the
+/// random choices made by simulated annealing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeuristicOptions {
+    pub seed: u64,
+    pub max_iterations: u64,
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for HeuristicOptions {
+    fn default() -> HeuristicOptions {
+        HeuristicOptions {
+            seed: 2015,
+            max_iterations: 1_000_000,
+            time_budget: None,
+        }
+    }
+}
+
+impl HeuristicOptions {
+    /// Returns the options with the given seed for the random choices made during the search.
+    pub fn with_seed(mut self, seed: u64) -> HeuristicOptions {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the options with the search stopped after the given number of iterations.
+    pub fn with_max_iterations(mut self, max_iterations: u64) -> HeuristicOptions {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Returns the options with the search stopped once the given time has passed.
+    pub fn with_time_budget(mut self, time_budget: Duration) -> HeuristicOptions {
+        self.time_budget = Some(time_budget);
+        self
+    }
+}
+
+/// Represents the best route found by a heuristic route solver, along with the number of
+/// iterations run and the time taken. The route is not guaranteed to be the best route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeuristicResult {
+    pub route: Route,
+    pub iterations: u64,
+    pub elapsed: Duration,
+}
+
+/// Tracks the iterations run and time taken by a heuristic search against its limits.
+struct SearchBudget {
+    max_iterations: u64,
+    time_budget: Option<Duration>,
+    started: Instant,
+    iterations: u64,
+}
+
+impl SearchBudget {
+    fn new(options: &HeuristicOptions) -> SearchBudget {
+        SearchBudget {
+            max_iterations: options.max_iterations,
+            time_budget: options.time_budget,
+            started: Instant::now(),
+            iterations: 0,
+        }
+    }
+
+    /// Starts the next iteration, returning false instead if the budget has been used up.
+    fn next_iteration(&mut self) -> bool {
+        if self.iterations >= self.max_iterations
+            || self
+                .time_budget
+                .is_some_and(|budget| self.started.elapsed() >= budget)
+        {
+            return false;
+        }
+        self.iterations += 1;
+        true
+    }
+
+    /// Returns the fraction of the budget used so far, from 0.0 to 1.0.
+    fn progress(&self) -> f64 {
+        let by_iterations = self.iterations as f64 / self.max_iterations.max(1) as f64;
+        let by_time = match self.time_budget {
+            Some(budget) if !budget.is_zero() => {
+                self.started.elapsed().as_secs_f64() / budget.as_secs_f64()
+            }
+            Some(_) => 1.0,
+            None => 0.0,
+        };
+        by_iterations.max(by_time).min(1.0)
+    }
+}
+
+impl RoutePlan {
+    /// Returns the total distance of the route visiting the nodes with the given indices in order,
+    /// or None if the route needs a leg missing from the graph.
+    fn order_distance(&self, order: &[usize]) -> Option<u64> {
+        order.windows(2).try_fold(0, |dist, pair| {
+            Some(dist + self.matrix.distances[pair[0]][pair[1]]?)
+        })
+    }
+
+    /// Returns the change in distance caused by reversing the part of the route between the given
+    /// positions (inclusive), or None if the reversed route needs a leg missing from the graph.
+    fn reversal_change(&self, order: &[usize], i: usize, j: usize) -> Option<i64> {
+        let leg = |a: usize, b: usize| self.matrix.distances[a][b].map(|leg| leg as i64);
+        let mut change = 0;
+        if i > 0 {
+            change += leg(order[i - 1], order[j])? - leg(order[i - 1], order[i])?;
+        }
+        if j + 1 < order.len() {
+            change += leg(order[i], order[j + 1])? - leg(order[j], order[j + 1])?;
+        }
+        // The legs within the reversed part only change if the graph is asymmetric
+        if !self.is_symmetric {
+            for k in i..j {
+                change += leg(order[k + 1], order[k])? - leg(order[k], order[k + 1])?;
+            }
+        }
+        Some(change)
+    }
+
+    /// Builds a route by starting at the given node and repeatedly moving to the nearest (or for
+    /// the longest route, furthest) node not yet visited. Returned value is None if the route
+    /// reaches a node without a leg to any of the remaining nodes.
+    fn nearest_neighbour_order(&self, objective: Objective, start: usize) -> Option<Vec<usize>> {
+        let n = self.matrix.len();
+        let mut order = vec![start];
+        let mut visited = vec![false; n];
+        visited[start] = true;
+        while order.len() < n {
+            let last = order[order.len() - 1];
+            let next = (0..n)
+                .filter(|&node| !visited[node])
+                .filter_map(|node| self.matrix.distances[last][node].map(|leg| (node, leg)))
+                .reduce(|a, b| if objective.is_better(b.1, a.1) { b } else { a })?
+                .0;
+            visited[next] = true;
+            order.push(next);
+        }
+        Some(order)
+    }
+
+    /// Improves the route by reversing parts of it while any reversal makes the route better,
+    /// stopping early if the search budget is used up. Returned value is the distance of the
+    /// improved route.
+    fn two_opt(
+        &self,
+        objective: Objective,
+        order: &mut [usize],
+        mut distance: u64,
+        budget: &mut SearchBudget,
+    ) -> u64 {
+        let n = order.len();
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n.saturating_sub(1) {
+                for j in (i + 1)..n {
+                    if !budget.next_iteration() {
+                        return distance;
+                    }
+                    let Some(change) = self.reversal_change(order, i, j) else {
+                        continue;
+                    };
+                    if objective.gain(change) > 0 {
+                        order[i..=j].reverse();
+                        distance = distance.checked_add_signed(change).unwrap();
+                        improved = true;
+                    }
+                }
+            }
+        }
+        distance
+    }
+}
+
+/// Finds a route visiting each node in the graph exactly once by starting from each node in turn
+/// and always moving to the nearest (or for the longest route, furthest) node not yet visited,
+/// keeping the best of these routes. Each starting node counts as one iteration. Returned value is
+/// None if no route was found, which can happen for a graph with missing distances even if a route
+/// exists.
+pub fn find_nearest_neighbour_route(
+    edges: &HashMap<String, HashMap<String, u64>>,
+    objective: Objective,
+) -> Option<HeuristicResult> {
+    let started = Instant::now();
+    let plan = RoutePlan::new(edges, &RouteConstraints::default()).ok()?;
+    let (order, _) = nearest_neighbour_routes(&plan, objective)?;
+    Some(HeuristicResult {
+        route: plan.route(&order),
+        iterations: plan.matrix.len() as u64,
+        elapsed: started.elapsed(),
+    })
+}
+
+/// Finds a route visiting each node in the graph exactly once by improving the best nearest
+/// neighbour route with 2-opt moves, reversing part of the route while doing so makes the route
+/// better. Each reversal tried counts as one iteration. Returned value is None if no starting route
+/// was found.
+pub fn find_two_opt_route(
+    edges: &HashMap<String, HashMap<String, u64>>,
+    objective: Objective,
+    options: &HeuristicOptions,
+) -> Option<HeuristicResult> {
+    let mut budget = SearchBudget::new(options);
+    let plan = RoutePlan::new(edges, &RouteConstraints::default()).ok()?;
+    let (mut order, distance) = nearest_neighbour_routes(&plan, objective)?;
+    plan.two_opt(objective, &mut order, distance, &mut budget);
+    Some(HeuristicResult {
+        route: plan.route(&order),
+        iterations: budget.iterations,
+        elapsed: budget.started.elapsed(),
+    })
+}
+
+/// Finds a route visiting each node in the graph exactly once using simulated annealing. Starting
+/// from the best nearest neighbour route, random parts of the route are reversed, with changes
+/// that make the route worse accepted with a probability that falls as the search goes on. The best
+/// route seen is then improved with 2-opt moves if the budget allows. Each reversal tried counts as
+/// one iteration, and the same seed and iteration limit always give the same route. Returned value
+/// is None if no starting route was found.
+pub fn find_annealed_route(
+    edges: &HashMap<String, HashMap<String, u64>>,
+    objective: Objective,
+    options: &HeuristicOptions,
+) -> Option<HeuristicResult> {
+    let mut budget = SearchBudget::new(options);
+    let plan = RoutePlan::new(edges, &RouteConstraints::default()).ok()?;
+    let (mut order, mut distance) = nearest_neighbour_routes(&plan, objective)?;
+    let (mut best_order, mut best_distance) = (order.clone(), distance);
+    let n = order.len();
+    // Start hot enough to accept a change of about the average leg distance
+    let initial_temperature = (distance as f64 / n.max(2) as f64).max(1.0);
+    let mut prng = Prng::new(options.seed);
+    while n > 2 && budget.next_iteration() {
+        let (i, j) = (prng.below(n), prng.below(n));
+        let (i, j) = (i.min(j), i.max(j));
+        if i == j {
+            continue;
+        }
+        let Some(change) = plan.reversal_change(&order, i, j) else {
+            continue;
+        };
+        let gain = objective.gain(change);
+        let temperature = initial_temperature * 0.001f64.powf(budget.progress());
+        if gain >= 0 || prng.next_f64() < (gain as f64 / temperature).exp() {
+            order[i..=j].reverse();
+            distance = distance.checked_add_signed(change).unwrap();
+            if objective.is_better(distance, best_distance) {
+                (best_order, best_distance) = (order.clone(), distance);
+            }
+        }
+    }
+    plan.two_opt(objective, &mut best_order, best_distance, &mut budget);
+    Some(HeuristicResult {
+        route: plan.route(&best_order),
+        iterations: budget.iterations,
+        elapsed: budget.started.elapsed(),
+    })
+}
+
+/// Returns the best of the nearest neighbour routes starting from each node, along with its
+/// distance. Returned value is None if no route was found.
+fn nearest_neighbour_routes(plan: &RoutePlan, objective: Objective) -> Option<(Vec<usize>, u64)> {
+    if plan.matrix.len() == 0 {
+        return Some((vec![], 0));
+    }
+    (0..plan.matrix.len())
+        .filter_map(|start| plan.nearest_neighbour_order(objective, start))
+        .map(|order| {
+            let distance = plan.order_distance(&order).unwrap();
+            (order, distance)
+        })
+        .reduce(|a, b| if objective.is_better(b.1, a.1) { b } else { a })
+}
+
+/// Finds the minimum and maximum distances needed to visit all nodes in the graph by trying every
+/// order of visiting the nodes. This takes O(n!) time, so is only kept as a reference for
+/// cross-checking the other solvers on small graphs. Returned value is a tuple containing the
+/// minimum and maximum distances found (in that order).
+pub fn find_min_max_distances_to_visit_all_nodes(
+    edges: &HashMap<String, HashMap<String, u64>>,
+) -> (Option<u64>, Option<u64>) {
+    let mut min_dist: Option<u64> = None;
+    let mut max_dist: Option<u64> = None;
+    // Try each of the possible orders to visit all nodes
+    let nodes = graph_nodes(edges);
+    for nodes in nodes.iter().permutations(nodes.len()) {
+        // Calculate the total distance between the nodes in the order visited, skipping orders
+        // that need a distance missing from the graph
+        let Some(dist) = nodes.windows(2).try_fold(0, |dist, pair| {
+            let leg = edges
+                .get(*pair[0])
+                .and_then(|to_nodes| to_nodes.get(*pair[1]))?;
+            Some(dist + leg)
+        }) else {
+            continue;
+        };
+        // Check if a new minimum or maximum distance has been found
+        if min_dist.is_none() || dist < min_dist.unwrap() {
+            min_dist = Some(dist);
+        }
+        if max_dist.is_none() || dist > max_dist.unwrap() {
+            max_dist = Some(dist);
+        }
+    }
+    (min_dist, max_dist)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 09 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day09_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(141, solution);
+    }
+
+    /// Tests the Day 09 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day09_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(736, solution);
+    }
+
+    /// Tests that the Held-Karp solver agrees with the permutation solver on the actual input and a
+    /// generated graph.
+    #[test]
+    fn test_day09_held_karp_matches_permutations() {
+        let actual = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let generated = generate_graph(9, |i, j| ((i * 37 + j * 91 + i * j * 13) % 97 + 1) as u64);
+        for edges in [actual, generated] {
+            let expected = find_min_max_distances_to_visit_all_nodes(&edges);
+            let found = (
+                find_route_distance(&edges, Objective::Shortest).ok(),
+                find_route_distance(&edges, Objective::Longest).ok(),
+            );
+            assert_eq!(expected, found);
+        }
+    }
+
+    /// Tests that the best route is returned with the distance of each leg.
+    #[test]
+    fn test_day09_find_best_route() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let route =
+            find_best_route(&edges, Objective::Shortest, &RouteConstraints::default()).unwrap();
+        assert_eq!(
+            "Belfast -(141)-> Dublin -(464)-> London = 605",
+            route.to_string()
+        );
+        let route =
+            find_best_route(&edges, Objective::Longest, &RouteConstraints::default()).unwrap();
+        let expected = Route {
+            nodes: ["Belfast", "London", "Dublin"].map(String::from).to_vec(),
+            legs: vec![518, 464],
+            distance: 982,
+        };
+        assert_eq!(expected, route);
+    }
+
+    /// Tests that the k best and worst routes are listed in order, without reversed duplicates.
+    #[test]
+    fn test_day09_find_k_best_routes() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let distances =
+            |routes: Vec<Route>| routes.iter().map(|r| r.distance).collect::<Vec<u64>>();
+        let routes =
+            find_k_best_routes(&edges, Objective::Shortest, &RouteConstraints::default(), 5)
+                .unwrap();
+        assert_eq!(vec![605, 659, 982], distances(routes));
+        let routes =
+            find_k_best_routes(&edges, Objective::Longest, &RouteConstraints::default(), 2)
+                .unwrap();
+        assert_eq!(vec![982, 659], distances(routes));
+        // Agrees with sorting every order of visiting the nodes on a larger graph
+        let edges = generate_graph(7, |i, j| ((i * 37 + j * 91 + i * j * 13) % 97 + 1) as u64);
+        let mut expected: Vec<u64> = edges
+            .keys()
+            .permutations(edges.len())
+            .filter(|nodes| nodes[0] < nodes[nodes.len() - 1])
+            .map(|nodes| nodes.windows(2).map(|pair| edges[pair[0]][pair[1]]).sum())
+            .collect();
+        expected.sort();
+        let routes = find_k_best_routes(
+            &edges,
+            Objective::Shortest,
+            &RouteConstraints::default(),
+            20,
+        )
+        .unwrap();
+        assert_eq!(expected[..20].to_vec(), distances(routes));
+        let routes =
+            find_k_best_routes(&edges, Objective::Longest, &RouteConstraints::default(), 20)
+                .unwrap();
+        expected.reverse();
+        assert_eq!(expected[..20].to_vec(), distances(routes));
+    }
+
+    /// Tests that routes are found with a pinned start or end node and without forbidden legs.
+    #[test]
+    fn test_day09_constrained_routes() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let constraints = RouteConstraints::default().with_start("London");
+        let route = find_best_route(&edges, Objective::Shortest, &constraints).unwrap();
+        assert_eq!(
+            "London -(464)-> Dublin -(141)-> Belfast = 605",
+            route.to_string()
+        );
+        let constraints = RouteConstraints::default().with_end("Dublin");
+        let route = find_best_route(&edges, Objective::Shortest, &constraints).unwrap();
+        assert_eq!(
+            "London -(518)-> Belfast -(141)-> Dublin = 659",
+            route.to_string()
+        );
+        let constraints = RouteConstraints::default().with_forbidden_leg("Dublin", "Belfast");
+        let routes = find_k_best_routes(&edges, Objective::Shortest, &constraints, 5).unwrap();
+        assert_eq!(1, routes.len());
+        assert_eq!(
+            "Belfast -(518)-> London -(464)-> Dublin = 982",
+            routes[0].to_string()
+        );
+        // Pinned nodes must be in the graph, and a route must remain once legs are forbidden
+        let constraints = RouteConstraints::default().with_start("Paris");
+        let expected = RouteError::UnknownNode {
+            node: String::from("Paris"),
+        };
+        assert_eq!(
+            Err(expected),
+            find_best_route(&edges, Objective::Shortest, &constraints)
+        );
+        let constraints = RouteConstraints::default()
+            .with_start("London")
+            .with_forbidden_leg("London", "Dublin")
+            .with_forbidden_leg("London", "Belfast");
+        assert_eq!(
+            Err(RouteError::NoRoute),
+            find_best_route(&edges, Objective::Shortest, &constraints)
+        );
+    }
+
+    /// Tests that round trips return to their start node and agree with trying every order of
+    /// visiting the nodes.
+    #[test]
+    fn test_day09_round_trips() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let constraints = RouteConstraints::default().with_round_trip();
+        let route = find_best_route(&edges, Objective::Shortest, &constraints).unwrap();
+        assert_eq!(
+            "Belfast -(141)-> Dublin -(464)-> London -(518)-> Belfast = 1123",
+            route.to_string()
+        );
+        let constraints = constraints.with_end("London");
+        let route = find_best_route(&edges, Objective::Longest, &constraints).unwrap();
+        assert_eq!(vec!["London", "Belfast", "Dublin", "London"], route.nodes);
+        assert_eq!(1123, route.distance);
+        let constraints = constraints.with_start("Dublin");
+        let expected = RouteError::ConflictingEnds {
+            start: String::from("Dublin"),
+            end: String::from("London"),
+        };
+        assert_eq!(
+            Err(expected),
+            find_best_route(&edges, Objective::Shortest, &constraints)
+        );
+        // Agrees with sorting every round trip on a larger graph, with each direction counted once
+        let edges = generate_graph(7, |i, j| ((i * 37 + j * 91 + i * j * 13) % 97 + 1) as u64);
+        let nodes: Vec<&String> = edges.keys().sorted().collect();
+        let mut expected: Vec<u64> = nodes[1..]
+            .iter()
+            .permutations(nodes.len() - 1)
+            .filter(|rest| rest[0] < rest[rest.len() - 1])
+            .map(|rest| {
+                let tour = [vec![&nodes[0]], rest, vec![&nodes[0]]].concat();
+                tour.windows(2).map(|pair| edges[*pair[0]][*pair[1]]).sum()
+            })
+            .collect();
+        expected.sort();
+        let constraints = RouteConstraints::default().with_round_trip();
+        let routes = find_k_best_routes(&edges, Objective::Shortest, &constraints, 20).unwrap();
+        let distances: Vec<u64> = routes.iter().map(|route| route.distance).collect();
+        assert_eq!(expected[..20].to_vec(), distances);
+        let route = find_best_route(&edges, Objective::Longest, &constraints).unwrap();
+        assert_eq!(expected.last().copied(), Some(route.distance));
+    }
+
+    /// Tests that self-loops, conflicting distances and missing distances are reported when the
+    /// input is parsed.
+    #[test]
+    fn test_day09_graph_validation() {
+        let err = parse_input("London to Dublin = 464\n  London to London = 5\n").unwrap_err();
+        assert_eq!((2, 3), (err.line(), err.column()));
+        assert_eq!("distance between two different nodes", err.expected());
+        let raw_input = format!(
+            "{}Dublin to London = 464\nDublin to London = 400\n",
+            EXAMPLES[0].input
+        );
+        let err = parse_input(&raw_input).unwrap_err();
+        assert_eq!(5, err.line());
+        assert_eq!(
+            "distance 464 from Dublin to London given earlier",
+            err.expected()
+        );
+        let err = parse_input("London to Dublin = 464\nLondon to Belfast = 518\n").unwrap_err();
+        assert_eq!((2, 11), (err.line(), err.column()));
+        assert_eq!("distance between Belfast and Dublin", err.expected());
+        let raw_input = "Paris to Rome = 1\nOslo to Rome = 2\nLima to Oslo = 3\n";
+        let err = parse_input(raw_input).unwrap_err();
+        assert_eq!((3, 1), (err.line(), err.column()));
+        assert_eq!(
+            "distances between Lima and Paris, between Lima and Rome, between Oslo and Paris",
+            err.expected()
+        );
+        // Graphs built by hand are checked for every problem
+        let mut edges = parse_input(EXAMPLES[0].input).unwrap();
+        edges.get_mut("Dublin").unwrap().remove("London");
+        edges
+            .get_mut("Belfast")
+            .unwrap()
+            .insert(String::from("Belfast"), 0);
+        let expected = vec![
+            GraphError::SelfLoop {
+                node: String::from("Belfast"),
+            },
+            GraphError::MissingDistance {
+                from: String::from("Dublin"),
+                to: String::from("London"),
+            },
+        ];
+        assert_eq!(expected, validate_graph(&edges));
+        assert_eq!(
+            "no distance from \"Dublin\" to \"London\"",
+            expected[1].to_string()
+        );
+    }
+
+    /// Tests that distances only apply in the given direction for an asymmetric graph.
+    #[test]
+    fn test_day09_asymmetric_graph() {
+        let raw_input = "Alpha to Beta = 1\nBeta to Alpha = 10\nBeta to Gamma = 1\n\
+                         Gamma to Beta = 10\nAlpha to Gamma = 10\nGamma to Alpha = 10\n";
+        assert!(parse_input(raw_input).is_err());
+        let edges = parse_input_with_mode(raw_input, GraphMode::Asymmetric).unwrap();
+        assert_eq!(Ok(2), find_route_distance(&edges, Objective::Shortest));
+        assert_eq!(Ok(20), find_route_distance(&edges, Objective::Longest));
+        assert_eq!(
+            (Some(2), Some(20)),
+            find_min_max_distances_to_visit_all_nodes(&edges)
+        );
+        // Routes in opposite directions are different routes
+        let constraints = RouteConstraints::default();
+        let routes = find_k_best_routes(&edges, Objective::Shortest, &constraints, 10).unwrap();
+        assert_eq!(6, routes.len());
+        let constraints = constraints.with_round_trip();
+        let route = find_best_route(&edges, Objective::Shortest, &constraints).unwrap();
+        assert_eq!(
+            "Alpha -(1)-> Beta -(1)-> Gamma -(10)-> Alpha = 12",
+            route.to_string()
+        );
+        // Each direction must still be given
+        let raw_input = "Alpha to Beta = 1\nBeta to Alpha = 10\nBeta to Gamma = 1\n";
+        let err = parse_input_with_mode(raw_input, GraphMode::Asymmetric).unwrap_err();
+        assert_eq!((3, 9), (err.line(), err.column()));
+        assert_eq!(
+            "distances from Alpha to Gamma, from Gamma to Alpha, from Gamma to Beta",
+            err.expected()
+        );
+    }
+
+    /// Tests that the heuristic solvers find the best route on small graphs, and that annealing
+    /// runs are reproduced by using the same seed.
+    #[test]
+    fn test_day09_heuristic_routes() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let result = find_nearest_neighbour_route(&edges, Objective::Shortest).unwrap();
+        assert_eq!(605, result.route.distance);
+        assert_eq!(3, result.iterations);
+        let options = HeuristicOptions::default().with_max_iterations(1000);
+        let result = find_two_opt_route(&edges, Objective::Longest, &options).unwrap();
+        assert_eq!(982, result.route.distance);
+        let edges = generate_graph(9, |i, j| ((i * 37 + j * 91 + i * j * 13) % 97 + 1) as u64);
+        let options = HeuristicOptions::default()
+            .with_seed(9)
+            .with_max_iterations(50_000);
+        for objective in [Objective::Shortest, Objective::Longest] {
+            let result = find_annealed_route(&edges, objective, &options).unwrap();
+            assert_eq!(
+                find_route_distance(&edges, objective),
+                Ok(result.route.distance)
+            );
+            assert_eq!(50_000, result.iterations);
+            let rerun = find_annealed_route(&edges, objective, &options).unwrap();
+            assert_eq!(result.route, rerun.route);
+        }
+    }
+
+    /// Tests that the heuristic solvers handle graphs too large for the exact solvers, and stop
+    /// once their iteration limit or time budget is used up.
+    #[test]
+    fn test_day09_heuristic_large_graph() {
+        // Nodes spaced around a circle, so the shortest route visits them in order around it
+        let edges = generate_graph(60, |i, j| i.abs_diff(j).min(60 - i.abs_diff(j)) as u64 * 10);
+        let nearest = find_nearest_neighbour_route(&edges, Objective::Shortest).unwrap();
+        assert_eq!(590, nearest.route.distance);
+        let options = HeuristicOptions::default().with_max_iterations(200_000);
+        let two_opt = find_two_opt_route(&edges, Objective::Longest, &options).unwrap();
+        let annealed = find_annealed_route(&edges, Objective::Longest, &options).unwrap();
+        let nearest = find_nearest_neighbour_route(&edges, Objective::Longest).unwrap();
+        assert!(two_opt.route.distance >= nearest.route.distance);
+        assert!(annealed.route.distance >= nearest.route.distance);
+        assert_eq!(60, annealed.route.nodes.len());
+        let options = options
+            .with_max_iterations(u64::MAX)
+            .with_time_budget(Duration::ZERO);
+        let result = find_annealed_route(&edges, Objective::Longest, &options).unwrap();
+        assert_eq!(0, result.iterations);
+        assert_eq!(nearest.route, result.route);
+    }
+
+    /// Tests that the Held-Karp solver handles a graph far too large for the permutation solver.
+    #[test]
+    fn test_day09_held_karp_large_graph() {
+        // Nodes spaced along a line, so the shortest route visits them in order
+        let edges = generate_graph(16, |i, j| i.abs_diff(j) as u64 * 10);
+        assert_eq!(Ok(150), find_route_distance(&edges, Objective::Shortest));
+        assert_eq!(Ok(1270), find_route_distance(&edges, Objective::Longest));
+    }
+
+    /// Tests that the Held-Karp solvers refuse graphs over the node limit instead of overflowing
+    /// the subset bitmask or running out of memory, leaving them to the heuristic solvers.
+    #[test]
+    fn test_day09_held_karp_node_limit() {
+        let constraints = RouteConstraints::default();
+        for node_count in [EXACT_ROUTE_NODE_LIMIT + 1, 64, 70] {
+            let edges = generate_graph(node_count, |i, j| i.abs_diff(j) as u64);
+            let expected = RouteError::TooManyNodes {
+                nodes: node_count,
+                limit: EXACT_ROUTE_NODE_LIMIT,
+            };
+            assert_eq!(
+                Err(expected),
+                find_best_route(&edges, Objective::Shortest, &constraints)
+            );
+            let expected = RouteError::TooManyNodes {
+                nodes: node_count,
+                limit: 17,
+            };
+            assert_eq!(
+                Err(expected),
+                find_k_best_routes(&edges, Objective::Shortest, &constraints, 3)
+            );
+            let options = HeuristicOptions::default().with_max_iterations(10_000);
+            let result = find_two_opt_route(&edges, Objective::Shortest, &options).unwrap();
+            assert_eq!(node_count as u64 - 1, result.route.distance);
+            // The day solvers fall back to the 2-opt heuristic
+            assert_eq!(node_count as u64 - 1, solve_part1(&edges));
+        }
+        // Routes must also be short enough for the tables to hold their distances
+        let edges = generate_graph(3, |_, _| u32::MAX as u64);
+        let expected = RouteError::DistanceTooLarge {
+            bound: 3 * u32::MAX as u64,
+            limit: u32::MAX as u64 - 1,
+        };
+        assert_eq!(
+            Err(expected),
+            find_best_route(&edges, Objective::Longest, &constraints)
+        );
+        assert_eq!(2 * u32::MAX as u64, solve_part2(&edges));
+    }
+
+    /// Tests that the k best routes solver completes at its node limit for a given k, and refuses
+    /// larger graphs instead of running out of memory.
+    #[test]
+    fn test_day09_k_best_node_limit() {
+        let constraints = RouteConstraints::default();
+        let edges = generate_graph(18, |i, j| i.abs_diff(j) as u64);
+        let routes = find_k_best_routes(&edges, Objective::Shortest, &constraints, 1).unwrap();
+        assert_eq!(17, routes[0].distance);
+        let expected = RouteError::TooManyNodes {
+            nodes: 18,
+            limit: 17,
+        };
+        assert_eq!(
+            Err(expected.clone()),
+            find_k_best_routes(&edges, Objective::Shortest, &constraints, 2)
+        );
+        // Round trips keep twice as many partial routes for each state
+        let round_trip = constraints.clone().with_round_trip();
+        assert_eq!(
+            Err(expected),
+            find_k_best_routes(&edges, Objective::Shortest, &round_trip, 1)
+        );
+        let edges = generate_graph(19, |i, j| i.abs_diff(j) as u64);
+        let expected = RouteError::TooManyNodes {
+            nodes: 19,
+            limit: 18,
+        };
+        assert_eq!(
+            Err(expected),
+            find_k_best_routes(&edges, Objective::Shortest, &constraints, 1)
+        );
+    }
+
+    /// Generates a complete graph with the given number of nodes, using the given function of the
+    /// node indices (smallest first) to determine the distance between each pair of nodes.
+    fn generate_graph(
+        node_count: usize,
+        distance: impl Fn(usize, usize) -> u64,
+    ) -> HashMap<String, HashMap<String, u64>> {
+        let mut edges: HashMap<String, HashMap<String, u64>> = HashMap::new();
+        for i in 0..node_count {
+            for j in (i + 1)..node_count {
+                let (node1, node2) = (format!("N{:02}", i), format!("N{:02}", j));
+                add_edges_between_nodes(&mut edges, &node1, &node2, distance(i, j));
+                add_edges_between_nodes(&mut edges, &node2, &node1, distance(i, j));
+            }
+        }
+        edges
+    }
+
+    /// Tests the Day 09 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day09_examples() {
+        assert_examples::<Day09>();
+    }
+}
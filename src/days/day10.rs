@@ -0,0 +1,159 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::{Param, Params, Solution};
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Elves Look, Elves Say";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day10.txt";
+pub const PROBLEM_DAY: u64 = 10;
+
+const PART1_ITERATIONS: Param = Param {
+    name: "part1_iterations",
+    default: 40,
+    description: "Number of look-and-say iterations applied in Part 1",
+    parse: None,
+};
+const PART2_ITERATIONS: Param = Param {
+    name: "part2_iterations",
+    default: 50,
+    description: "Number of look-and-say iterations applied in Part 2",
+    parse: None,
+};
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("1", Some("2"), None).with_params(&[("part1_iterations", 1)]),
+    Example::new("21", Some("4"), None).with_params(&[("part1_iterations", 1)]),
+    Example::new("111221", Some("6"), None).with_params(&[("part1_iterations", 1)]),
+    Example::new("1", Some("6"), None).with_params(&[("part1_iterations", 5)]),
+];
+
+/// Solution for AOC 2015 Day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMS: &'static [Param] = &[PART1_ITERATIONS, PART2_ITERATIONS];
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Part1 {
+        apply_lookandsay(input, params.get(&PART1_ITERATIONS))
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Part2 {
+        apply_lookandsay(input, params.get(&PART2_ITERATIONS))
+    }
+}
+
+/// Processes the AOC 2015 Day 10 input file into the format required by the solver functions.
+/// Returned value is vector of chars given in the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<char>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 10 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<char>, ParseError> {
+    let offset = raw_input.len() - raw_input.trim_start().len();
+    let seq = raw_input.trim().chars().collect::<Vec<char>>();
+    if let Some(i) = raw_input.trim().find(|c: char| !c.is_ascii_digit()) {
+        let found = raw_input.trim()[i..].chars().next().unwrap().to_string();
+        return Err(ParseError::at_offset(
+            raw_input,
+            offset + i,
+            "digit",
+            &found,
+        ));
+    }
+    Ok(seq)
+}
+
+/// Solves AOC 2015 Day 10 Part 1 // Determines the length of the character sequence after applying
+/// 40 iterations of the "look-and-say" transformation.
+pub fn solve_part1(seq: &[char]) -> usize {
+    apply_lookandsay(seq, PART1_ITERATIONS.default)
+}
+
+/// Solves AOC 2015 Day 10 Part 2 // Determines the length of the character sequence after applying
+/// 50 iterations of the "look-and-say" transformation.
+pub fn solve_part2(seq: &[char]) -> usize {
+    apply_lookandsay(seq, PART2_ITERATIONS.default)
+}
+
+/// Determines the length of the character sequence resulting from applying N iterations of the
+/// look-and-say transformation.
+fn apply_lookandsay(seq: &[char], n: u64) -> usize {
+    let mut seq_transform = seq.to_vec();
+    for _ in 0..n {
+        let mut seq_new: Vec<char> = vec![];
+        let mut i_left: usize = 0;
+        let mut i_right: usize = 0;
+        while i_left < seq_transform.len() {
+            // Find end of current run of numbers
+            while i_right < seq_transform.len() {
+                if seq_transform[i_right] == seq_transform[i_left] {
+                    i_right += 1;
+                } else {
+                    break;
+                }
+            }
+            // Update the new sequence based on the current run of characters
+            seq_new.extend((i_right - i_left).to_string().chars());
+            seq_new.push(seq_transform[i_left]);
+            // Move to next run of characters
+            i_left = i_right;
+        }
+        // Update the character sequence
+        seq_transform = seq_new;
+    }
+    seq_transform.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 10 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day10_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(329356, solution);
+    }
+
+    /// Tests the Day 10 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day10_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(4666278, solution);
+    }
+
+    /// Tests the Day 10 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day10_examples() {
+        assert_examples::<Day10>();
+    }
+}
@@ -0,0 +1,110 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::bespoke::PasswordGenerator;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Corporate Policy";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day11.txt";
+pub const PROBLEM_DAY: u64 = 11;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("abcdefgh", Some("abcdffaa"), None),
+    Example::new("ghijklmn", Some("ghjaabcc"), None),
+];
+
+/// Solution for AOC 2015 Day 11.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = String;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 11 input file into the format required by the solver functions.
+/// Returned value is password seed string given in input file.
+pub fn process_input_file(filename: &str) -> Result<String, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 11 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<String, ParseError> {
+    let offset = raw_input.len() - raw_input.trim_start().len();
+    let seed = raw_input.trim();
+    if let Some(i) = seed.find(|c: char| !c.is_ascii_lowercase()) {
+        let found = seed[i..].chars().next().unwrap().to_string();
+        return Err(ParseError::at_offset(
+            raw_input,
+            offset + i,
+            "lowercase letter",
+            &found,
+        ));
+    }
+    Ok(seed.to_string())
+}
+
+/// Solves AOC 2015 Day 11 Part 1 // Gets the next valid password from the current seed password.
+pub fn solve_part1(seed: &str) -> String {
+    let mut password_gen = PasswordGenerator::new(&seed.chars().collect::<Vec<char>>());
+    password_gen.next().unwrap()
+}
+
+/// Solves AOC 2015 Day 11 Part 2 // Gets the second next valid password from the current seed
+/// password.
+pub fn solve_part2(seed: &str) -> String {
+    let mut password_gen = PasswordGenerator::new(&seed.chars().collect::<Vec<char>>());
+    password_gen.next();
+    password_gen.next().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 11 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day11_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(String::from("hepxxyzz"), solution);
+    }
+
+    /// Tests the Day 11 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day11_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(String::from("heqaabcc"), solution);
+    }
+
+    /// Tests the Day 11 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day11_examples() {
+        assert_examples::<Day11>();
+    }
+}
@@ -0,0 +1,147 @@
+use std::fs;
+
+use fancy_regex::Regex;
+use serde_json::Value;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "JSAbacusFramework.io";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day12.txt";
+pub const PROBLEM_DAY: u64 = 12;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("[1,2,3]", Some("6"), Some("6")),
+    Example::new(r#"{"a":2,"b":4}"#, Some("6"), None),
+    Example::new("[[[3]]]", Some("3"), None),
+    Example::new(r#"{"a":{"b":4},"c":-1}"#, Some("3"), None),
+    Example::new(r#"{"a":[-1,1]}"#, Some("0"), None),
+    Example::new(r#"[-1,{"a":1}]"#, Some("0"), None),
+    Example::new("[]", Some("0"), None),
+    Example::new("{}", Some("0"), None),
+    Example::new(r#"[1,{"c":"red","b":2},3]"#, None, Some("4")),
+    Example::new(r#"{"d":"red","e":[1,2,3,4],"f":5}"#, None, Some("0")),
+    Example::new(r#"[1,"red",5]"#, None, Some("6")),
+];
+
+/// Solution for AOC 2015 Day 12.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = String;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 12 input file into the format required by the solver functions.
+/// Returned value is the json-formatted string given in the input file.
+pub fn process_input_file(filename: &str) -> Result<String, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 12 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<String, ParseError> {
+    if let Err(err) = serde_json::from_str::<Value>(raw_input) {
+        return Err(ParseError::new(
+            err.line(),
+            err.column(),
+            "valid JSON",
+            &err.to_string(),
+        ));
+    }
+    Ok(raw_input.trim().to_string())
+}
+
+/// Solves AOC 2015 Day 12 Part 1 // Determines the sum of all numbers in the given json string.
+pub fn solve_part1(json: &str) -> i64 {
+    let regex_number = Regex::new(r"(-?\d+)").unwrap();
+    regex_number
+        .find_iter(json)
+        .map(|n| n.unwrap().as_str().parse::<i64>().unwrap())
+        .sum::<i64>()
+}
+
+/// Solves AOC 2015 Day 12 Part 2 // Determines the sum of all valid numbers in the given json
+/// string. Valid numbers are those not contained within JSON objects (or their children) as values
+/// that also have the string "red" as a value.
+pub fn solve_part2(json: &str) -> i64 {
+    let v: Value = serde_json::from_str(json).unwrap();
+    sum_valid_numbers_in_json_value(&v)
+}
+
+/// Adds up all of the valid numbers in the JSON value. Valid number are those not contained within
+/// JSON objects (or their children) as values that also have the string "red" as a value.
+fn sum_valid_numbers_in_json_value(v: &Value) -> i64 {
+    let mut total = 0;
+    match v {
+        Value::Array(arr) => {
+            for elem in arr {
+                total += sum_valid_numbers_in_json_value(elem);
+            }
+        }
+        Value::Object(map) => {
+            for (_key, elem) in map.iter() {
+                // Exclude invalid object (includes string "red" as a value)
+                if elem.is_string() && elem.as_str().unwrap() == "red" {
+                    total = 0;
+                    break;
+                }
+                total += sum_valid_numbers_in_json_value(elem);
+            }
+        }
+        Value::Number(n) => return n.as_i64().unwrap(),
+        _ => (),
+    }
+    total
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 12 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day12_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(156366, solution);
+    }
+
+    /// Tests the Day 12 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day12_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(96852, solution);
+    }
+
+    /// Tests the Day 12 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day12_examples() {
+        assert_examples::<Day12>();
+    }
+}
@@ -0,0 +1,927 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::slice;
+
+use fancy_regex::Regex;
+use itertools::Itertools;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::{parse_capture, ParseError, Prng};
+
+pub const PROBLEM_NAME: &str = "Knights of the Dinner Table";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day13.txt";
+pub const PROBLEM_DAY: u64 = 13;
+
+const PROTAGONIST_NAME: &str = "Mr. Robot";
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "Alice would gain 54 happiness units by sitting next to Bob.\n\
+     Alice would lose 79 happiness units by sitting next to Carol.\n\
+     Alice would lose 2 happiness units by sitting next to David.\n\
+     Bob would gain 83 happiness units by sitting next to Alice.\n\
+     Bob would lose 7 happiness units by sitting next to Carol.\n\
+     Bob would lose 63 happiness units by sitting next to David.\n\
+     Carol would lose 62 happiness units by sitting next to Alice.\n\
+     Carol would gain 60 happiness units by sitting next to Bob.\n\
+     Carol would gain 55 happiness units by sitting next to David.\n\
+     David would gain 46 happiness units by sitting next to Alice.\n\
+     David would lose 7 happiness units by sitting next to Bob.\n\
+     David would gain 41 happiness units by sitting next to Carol.\n",
+    Some("330"),
+    None,
+)];
+
+/// Solution for AOC 2015 Day 13.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = HashMap<String, HashMap<String, i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 13 input file into the format required by the solver functions.
+/// Returned value is hashmap mapping each person to other people and the associated change in
+/// happiness level if they sit next to each other.
+pub fn process_input_file(
+    filename: &str,
+) -> Result<HashMap<String, HashMap<String, i64>>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 13 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<HashMap<String, HashMap<String, i64>>, ParseError> {
+    let mut edges: HashMap<String, HashMap<String, i64>> = HashMap::new();
+    let regex_line = Regex::new(concat!(
+        r#"^([[:alpha:]]+) would (gain|lose) (\d+) happiness unit[s]? by "#,
+        r#"sitting next to ([[:alpha:]]+).$"#,
+    ))
+    .unwrap();
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Ok(Some(caps)) = regex_line.captures(line) {
+            let name_from = &caps[1];
+            let name_to = &caps[4];
+            let points = parse_capture::<i64>(&caps, 3, i + 1, column, "happiness units")?;
+            let points = match &caps[2] {
+                "gain" => points,
+                "lose" => -points,
+                _ => {
+                    let column = column + caps.get(2).unwrap().start();
+                    return Err(ParseError::new(i + 1, column, "gain or lose", &caps[2]));
+                }
+            };
+            if let Entry::Vacant(e) = edges.entry(name_from.to_string()) {
+                e.insert(HashMap::from([(name_to.to_string(), points)]));
+            } else {
+                edges
+                    .get_mut(name_from)
+                    .unwrap()
+                    .insert(name_to.to_string(), points);
+            }
+        } else {
+            return Err(ParseError::new(i + 1, column, regex_line.as_str(), line));
+        }
+    }
+    Ok(edges)
+}
+
+/// Solves AOC 2015 Day 13 Part 1 // Determines the total change in happiness for the optimal
+/// seating arrangement of the actual guest list.
+pub fn solve_part1(edges: &HashMap<String, HashMap<String, i64>>) -> i64 {
+    find_max_happiness_delta(edges)
+}
+
+/// Solves AOC 2015 Day 13 Part 2 // Determines the total change in happiness for the optimal
+/// seating arrangement after the protagonist is added to the guest list.
+pub fn solve_part2(edges: &HashMap<String, HashMap<String, i64>>) -> i64 {
+    let edges = insert_new_attendee(edges, PROTAGONIST_NAME);
+    find_max_happiness_delta(&edges)
+}
+
+/// Returns the updated edges map after inserting the new attendee with given name.
+fn insert_new_attendee(
+    edges: &HashMap<String, HashMap<String, i64>>,
+    new_name: &str,
+) -> HashMap<String, HashMap<String, i64>> {
+    // Put new person in existing edge records
+    let mut edges = edges.clone();
+    for value in edges.values_mut() {
+        value.insert(new_name.to_string(), 0);
+    }
+    // Insert edge record from the new name
+    let mut new_name_edges: HashMap<String, i64> = HashMap::new();
+    for name in edges.keys() {
+        new_name_edges.insert(name.to_string(), 0);
+    }
+    edges.insert(new_name.to_string(), new_name_edges);
+    edges
+}
+
+/// Determines the maximum change in happiness possible for a seating arrangement of the people
+/// named in the given graph edges.
+fn find_max_happiness_delta(edges: &HashMap<String, HashMap<String, i64>>) -> i64 {
+    if let Some(plan) = find_best_seating(edges, &SeatingConstraints::default()) {
+        return plan.total;
+    }
+    panic!("Did not find the maximum happiness change!");
+}
+
+/// Represents an arrangement of guests around a circular table, starting from the first seat, along
+/// with the change in happiness of each guest from sitting next to their two neighbours and the
+/// total change in happiness.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeatingPlan {
+    pub guests: Vec<String>,
+    pub contributions: Vec<i64>,
+    pub total: i64,
+}
+
+impl SeatingPlan {
+    /// Creates the seating plan for the guests seated in the given order around the table. Changes
+    /// in happiness not given in the graph edges are taken to be 0.
+    fn new(edges: &HashMap<String, HashMap<String, i64>>, guests: Vec<String>) -> SeatingPlan {
+        let contributions: Vec<i64> = (0..guests.len())
+            .map(|i| {
+                let left = &guests[(i + guests.len() - 1) % guests.len()];
+                let right = &guests[(i + 1) % guests.len()];
+                happiness(edges, &guests[i], left) + happiness(edges, &guests[i], right)
+            })
+            .collect();
+        SeatingPlan {
+            guests,
+            total: contributions.iter().sum(),
+            contributions,
+        }
+    }
+}
+
+impl fmt::Display for SeatingPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (guest, contribution)) in self.guests.iter().zip(&self.contributions).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} ({:+})", guest, contribution)?;
+        }
+        write!(f, " = {}", self.total)
+    }
+}
+
+/// Constraints on the seating arrangements considered by the seating solver. By default, any guest
+/// may sit next to any other guest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeatingConstraints {
+    pub together: Vec<(String, String)>,
+    pub apart: Vec<(String, String)>,
+    pub host: Option<String>,
+}
+
+impl SeatingConstraints {
+    /// Returns the constraints with the given guests required to sit next to each other.
+    pub fn with_together(mut self, guest1: &str, guest2: &str) -> SeatingConstraints {
+        self.together.push((guest1.to_string(), guest2.to_string()));
+        self
+    }
+
+    /// Returns the constraints with the given guests required not to sit next to each other.
+    pub fn with_apart(mut self, guest1: &str, guest2: &str) -> SeatingConstraints {
+        self.apart.push((guest1.to_string(), guest2.to_string()));
+        self
+    }
+
+    /// Returns the constraints with the given guest seated in the first seat of the table.
+    pub fn with_host(mut self, guest: &str) -> SeatingConstraints {
+        self.host = Some(guest.to_string());
+        self
+    }
+
+    /// Checks if every guest named by the constraints is one of the given guests.
+    fn names_known(&self, guests: &[&String]) -> bool {
+        self.together
+            .iter()
+            .chain(self.apart.iter())
+            .flat_map(|(guest1, guest2)| [guest1, guest2])
+            .chain(self.host.iter())
+            .all(|guest| guests.contains(&guest))
+    }
+}
+
+/// Finds the seating arrangement with the maximum total change in happiness that meets the given
+/// constraints, by trying each order of seating the other guests after the guest in the first seat.
+/// The host sits in the first seat if given, otherwise the guest with the earliest name does.
+/// Returned value is None if a guest named by the constraints is not in the guest list or no
+/// arrangement meets the constraints.
+pub fn find_best_seating(
+    edges: &HashMap<String, HashMap<String, i64>>,
+    constraints: &SeatingConstraints,
+) -> Option<SeatingPlan> {
+    let guests = guest_list(edges);
+    if !constraints.names_known(&guests) {
+        return None;
+    }
+    if guests.is_empty() {
+        return Some(SeatingPlan::new(edges, vec![]));
+    }
+    // Rotating an arrangement around the table gives the same arrangement, so fix the first seat
+    let first = constraints.host.as_ref().unwrap_or(guests[0]);
+    let others: Vec<&String> = guests
+        .iter()
+        .copied()
+        .filter(|&guest| guest != first)
+        .collect();
+    let mut best: Option<(i64, Vec<&String>)> = None;
+    for others in others.iter().copied().permutations(others.len()) {
+        let order: Vec<&String> = [vec![first], others].concat();
+        if count_violations(constraints, slice::from_ref(&order)) > 0 {
+            continue;
+        }
+        let total = table_happiness(edges, &order);
+        if best
+            .as_ref()
+            .is_none_or(|(best_total, _)| total > *best_total)
+        {
+            best = Some((total, order));
+        }
+    }
+    let (_, order) = best?;
+    let guests = order.into_iter().cloned().collect();
+    Some(SeatingPlan::new(edges, guests))
+}
+
+/// Returns the change in happiness of the guest from sitting next to the other guest, which is 0 if
+/// not given in the graph edges.
+fn happiness(edges: &HashMap<String, HashMap<String, i64>>, guest: &str, other: &str) -> i64 {
+    edges
+        .get(guest)
+        .and_then(|to_guests| to_guests.get(other))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Largest number of guests seated across several tables by exact search, with larger guest lists
+/// seated by simulated annealing instead.
+pub const EXACT_TABLE_SEATING_LIMIT: usize = 10;
+/// Seed used by the simulated annealing seating solver when no other seed is given.
+pub const DEFAULT_SEATING_SEED: u64 = 2015;
+/// Number of iterations used by the simulated annealing seating solver when no other number is
+/// given.
+pub const DEFAULT_SEATING_ITERATIONS: u64 = 500_000;
+
+/// Represents an arrangement of guests across several circular tables, with the seating plan of
+/// each table in the order the table capacities were given and the total change in happiness.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSeating {
+    pub tables: Vec<SeatingPlan>,
+    pub total: i64,
+}
+
+impl TableSeating {
+    fn new(tables: Vec<SeatingPlan>) -> TableSeating {
+        TableSeating {
+            total: tables.iter().map(|plan| plan.total).sum(),
+            tables,
+        }
+    }
+}
+
+impl fmt::Display for TableSeating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, plan) in self.tables.iter().enumerate() {
+            writeln!(f, "Table {}: {}", i + 1, plan)?;
+        }
+        write!(f, "Total = {}", self.total)
+    }
+}
+
+/// Finds the arrangement of guests across tables with the given capacities that has the maximum
+/// total change in happiness and meets the given constraints. Guests required to sit together must
+/// be at the same table, and the host sits in the first seat of the first table. Guest lists up to
+/// [`EXACT_TABLE_SEATING_LIMIT`] guests are searched exactly, with larger lists using simulated
+/// annealing with the default seed and number of iterations. Returned value is None if there are
+/// not enough seats, a guest named by the constraints is not in the guest list, or no arrangement
+/// meeting the constraints was found.
+pub fn find_table_seating(
+    edges: &HashMap<String, HashMap<String, i64>>,
+    capacities: &[usize],
+    constraints: &SeatingConstraints,
+) -> Option<TableSeating> {
+    if guest_list(edges).len() <= EXACT_TABLE_SEATING_LIMIT {
+        find_exact_table_seating(edges, capacities, constraints)
+    } else {
+        find_annealed_table_seating(
+            edges,
+            capacities,
+            constraints,
+            DEFAULT_SEATING_SEED,
+            DEFAULT_SEATING_ITERATIONS,
+        )
+    }
+}
+
+/// Finds the best arrangement of guests across tables with the given capacities by trying each way
+/// of assigning the guests to the tables, along with the best arrangement around each table for
+/// the guests assigned to it. Tables with the same capacity are interchangeable, so only one of the
+/// assignments differing by swapping the guests of such tables is tried. The time taken grows
+/// exponentially with the number of guests, so returned value is None for more than
+/// [`EXACT_TABLE_SEATING_LIMIT`] guests (see [`find_annealed_table_seating`]), as well as if there
+/// are not enough seats, a guest named by the constraints is not in the guest list, or no
+/// arrangement meets the constraints.
+pub fn find_exact_table_seating(
+    edges: &HashMap<String, HashMap<String, i64>>,
+    capacities: &[usize],
+    constraints: &SeatingConstraints,
+) -> Option<TableSeating> {
+    let mut guests = guest_list(edges);
+    if guests.len() > EXACT_TABLE_SEATING_LIMIT
+        || !constraints.names_known(&guests)
+        || guests.len() > capacities.iter().sum()
+    {
+        return None;
+    }
+    // Assign the host first, so the host is always assigned to the first table
+    if let Some(host) = &constraints.host {
+        guests.retain(|&guest| guest != host);
+        guests.insert(0, host);
+    }
+    let mut search = TableSearch {
+        edges,
+        constraints,
+        guests: &guests,
+        capacities,
+        tables: vec![0; capacities.len()],
+        best_plans: HashMap::new(),
+        best: None,
+    };
+    search.assign(0);
+    search.best.map(|(_, tables)| {
+        let plans = tables
+            .iter()
+            .map(|&table| search.best_plans[&table].clone().unwrap())
+            .collect();
+        TableSeating::new(plans)
+    })
+}
+
+/// State of the exact search for the best arrangement of guests across tables.
+struct TableSearch<'a> {
+    edges: &'a HashMap<String, HashMap<String, i64>>,
+    constraints: &'a SeatingConstraints,
+    guests: &'a [&'a String],
+    capacities: &'a [usize],
+    tables: Vec<usize>, // bitmask of the guests assigned to each table so far
+    best_plans: HashMap<usize, Option<SeatingPlan>>, // best plan for each set of guests at a table
+    best: Option<(i64, Vec<usize>)>,
+}
+
+impl TableSearch<'_> {
+    /// Tries each table for the guest with the given index, then for each of the remaining guests.
+    fn assign(&mut self, guest: usize) {
+        if guest == self.guests.len() {
+            self.check_assignment();
+            return;
+        }
+        for table in 0..self.capacities.len() {
+            // The host is assigned first and always sits at the first table
+            if guest == 0 && table > 0 && self.constraints.host.is_some() {
+                break;
+            }
+            let seated = self.tables[table].count_ones() as usize;
+            if seated >= self.capacities[table] {
+                continue;
+            }
+            // An empty table is interchangeable with an earlier empty table of the same capacity
+            if seated == 0
+                && (0..table).any(|other| {
+                    self.tables[other] == 0 && self.capacities[other] == self.capacities[table]
+                })
+            {
+                continue;
+            }
+            self.tables[table] |= 1 << guest;
+            self.assign(guest + 1);
+            self.tables[table] ^= 1 << guest;
+        }
+    }
+
+    /// Checks if assigning the guests to the tables as they are now gives the best arrangement so
+    /// far.
+    fn check_assignment(&mut self) {
+        let table_of = |guest: &String| {
+            let index = self
+                .guests
+                .iter()
+                .position(|&other| other == guest)
+                .unwrap();
+            self.tables
+                .iter()
+                .position(|table| table & (1 << index) != 0)
+        };
+        if self
+            .constraints
+            .together
+            .iter()
+            .any(|(guest1, guest2)| table_of(guest1) != table_of(guest2))
+        {
+            return;
+        }
+        let mut total = 0;
+        for table in self.tables.clone() {
+            match self.best_plan(table) {
+                Some(plan_total) => total += plan_total,
+                None => return,
+            }
+        }
+        if self
+            .best
+            .as_ref()
+            .is_none_or(|(best_total, _)| total > *best_total)
+        {
+            self.best = Some((total, self.tables.clone()));
+        }
+    }
+
+    /// Returns the total change in happiness of the best arrangement around a table of the given
+    /// set of guests, or None if no arrangement meets the constraints.
+    fn best_plan(&mut self, table: usize) -> Option<i64> {
+        if !self.best_plans.contains_key(&table) {
+            let guests: Vec<&String> = (0..self.guests.len())
+                .filter(|i| table & (1 << i) != 0)
+                .map(|i| self.guests[i])
+                .collect();
+            let edges: HashMap<String, HashMap<String, i64>> = guests
+                .iter()
+                .map(|&guest| {
+                    let to_guests = guests
+                        .iter()
+                        .map(|&other| (other.to_string(), happiness(self.edges, guest, other)))
+                        .collect();
+                    (guest.to_string(), to_guests)
+                })
+                .collect();
+            // Only the constraints between the guests at the table apply to the table
+            let at_table = |guest: &String| guests.contains(&guest);
+            let constraints = SeatingConstraints {
+                together: (self.constraints.together.iter())
+                    .filter(|(guest1, guest2)| at_table(guest1) && at_table(guest2))
+                    .cloned()
+                    .collect(),
+                apart: (self.constraints.apart.iter())
+                    .filter(|(guest1, guest2)| at_table(guest1) && at_table(guest2))
+                    .cloned()
+                    .collect(),
+                host: self.constraints.host.clone().filter(at_table),
+            };
+            let plan = find_best_seating(&edges, &constraints);
+            self.best_plans.insert(table, plan);
+        }
+        self.best_plans[&table].as_ref().map(|plan| plan.total)
+    }
+}
+
+/// Finds an arrangement of guests across tables with the given capacities using simulated
+/// annealing. Each seat at each table holds a guest or is empty, and each iteration swaps the
+/// occupants of two random seats, so guests are moved around their table and between tables.
+/// Swaps that make the arrangement worse are accepted with a probability that falls as the search
+/// goes on, and arrangements that do not meet the constraints are penalised so heavily that any
+/// arrangement meeting them is better. The same seed and number of iterations always give the same
+/// arrangement, which is not guaranteed to be the best arrangement.
+pub fn find_annealed_table_seating(
+    edges: &HashMap<String, HashMap<String, i64>>,
+    capacities: &[usize],
+    constraints: &SeatingConstraints,
+    seed: u64,
+    iterations: u64,
+) -> Option<TableSeating> {
+    let guests = guest_list(edges);
+    let seat_count: usize = capacities.iter().sum();
+    if !constraints.names_known(&guests) || guests.len() > seat_count {
+        return None;
+    }
+    let mut seating = SeatAssignment::new(edges, &guests, capacities, constraints);
+    // Start with the guests filling the seats in name order, with the host in the first seat
+    if let Some(host) = &constraints.host {
+        if capacities.first().is_none_or(|&capacity| capacity == 0) {
+            return None;
+        }
+        let host = guests.iter().position(|&guest| guest == host).unwrap();
+        seating.seats[..=host].rotate_right(1);
+    }
+    let penalty = 1 + 2 * seating
+        .pairs
+        .iter()
+        .flatten()
+        .map(|units| units.abs())
+        .sum::<i64>();
+    let score =
+        |seating: &SeatAssignment, total: i64| total - penalty * seating.count_violations() as i64;
+    let mut table_totals: Vec<i64> = (0..capacities.len())
+        .map(|table| seating.table_happiness(table))
+        .collect();
+    let mut total: i64 = table_totals.iter().sum();
+    let mut current = score(&seating, total);
+    let (mut best_seats, mut best) = (seating.seats.clone(), current);
+    // Start hot enough to accept a swap costing about the largest change in happiness
+    let initial_temperature = seating
+        .pairs
+        .iter()
+        .flatten()
+        .map(|units| units.abs())
+        .max();
+    let initial_temperature = initial_temperature.unwrap_or(0).max(1) as f64;
+    // The host never leaves the first seat
+    let movable = match constraints.host {
+        Some(_) => 1..seat_count,
+        None => 0..seat_count,
+    };
+    let mut prng = Prng::new(seed);
+    for iteration in 0..iterations {
+        if movable.len() < 2 {
+            break;
+        }
+        let i = movable.start + prng.below(movable.len());
+        let j = movable.start + prng.below(movable.len());
+        if seating.seats[i] == seating.seats[j] {
+            continue;
+        }
+        // Only the tables holding the swapped seats change
+        let tables = [seating.table_of_seat[i], seating.table_of_seat[j]];
+        seating.seats.swap(i, j);
+        let new_totals = tables.map(|table| seating.table_happiness(table));
+        let new_total = match tables[0] == tables[1] {
+            true => total - table_totals[tables[0]] + new_totals[0],
+            false => {
+                total - table_totals[tables[0]] - table_totals[tables[1]]
+                    + new_totals[0]
+                    + new_totals[1]
+            }
+        };
+        let candidate = score(&seating, new_total);
+        let progress = iteration as f64 / iterations as f64;
+        let temperature = initial_temperature * 0.001f64.powf(progress);
+        if candidate >= current
+            || prng.next_f64() < ((candidate - current) as f64 / temperature).exp()
+        {
+            (current, total) = (candidate, new_total);
+            table_totals[tables[0]] = new_totals[0];
+            table_totals[tables[1]] = new_totals[1];
+            if current > best {
+                (best_seats, best) = (seating.seats.clone(), current);
+            }
+        } else {
+            seating.seats.swap(i, j);
+        }
+    }
+    seating.seats = best_seats;
+    if seating.count_violations() > 0 {
+        return None;
+    }
+    let plans = (0..capacities.len())
+        .map(|table| {
+            let table = seating.table_guests(table);
+            SeatingPlan::new(
+                edges,
+                table.map(|guest| guests[guest].to_string()).collect(),
+            )
+        })
+        .collect();
+    Some(TableSeating::new(plans))
+}
+
+/// Assignment of guests (by index into the guest list) to the seats of several tables, used by the
+/// simulated annealing seating solver.
+struct SeatAssignment {
+    seats: Vec<Option<usize>>,
+    tables: Vec<Range<usize>>, // seats at each table
+    table_of_seat: Vec<usize>,
+    pairs: Vec<Vec<i64>>, // change in happiness of both guests from sitting next to each other
+    together: Vec<(usize, usize)>,
+    apart: Vec<(usize, usize)>,
+}
+
+impl SeatAssignment {
+    /// Creates a new SeatAssignment with the guests in the first seats, in guest list order.
+    fn new(
+        edges: &HashMap<String, HashMap<String, i64>>,
+        guests: &[&String],
+        capacities: &[usize],
+        constraints: &SeatingConstraints,
+    ) -> SeatAssignment {
+        let mut seats: Vec<Option<usize>> = (0..guests.len()).map(Some).collect();
+        seats.resize(capacities.iter().sum(), None);
+        let mut tables: Vec<Range<usize>> = vec![];
+        let mut table_of_seat: Vec<usize> = vec![];
+        for (table, &capacity) in capacities.iter().enumerate() {
+            let start = table_of_seat.len();
+            tables.push(start..start + capacity);
+            table_of_seat.resize(start + capacity, table);
+        }
+        let pairs = guests
+            .iter()
+            .map(|&guest| {
+                guests
+                    .iter()
+                    .map(|&other| happiness(edges, guest, other) + happiness(edges, other, guest))
+                    .collect()
+            })
+            .collect();
+        let index = |guest: &String| guests.iter().position(|&other| other == guest).unwrap();
+        let indices = |pairs: &[(String, String)]| {
+            pairs
+                .iter()
+                .map(|(guest1, guest2)| (index(guest1), index(guest2)))
+                .collect()
+        };
+        SeatAssignment {
+            seats,
+            tables,
+            table_of_seat,
+            pairs,
+            together: indices(&constraints.together),
+            apart: indices(&constraints.apart),
+        }
+    }
+
+    /// Returns the guests seated at the table, in seat order.
+    fn table_guests(&self, table: usize) -> impl Iterator<Item = usize> + '_ {
+        self.seats[self.tables[table].clone()]
+            .iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Returns the total change in happiness of the guests seated at the table.
+    fn table_happiness(&self, table: usize) -> i64 {
+        let mut guests = self.table_guests(table);
+        let Some(first) = guests.next() else {
+            return 0;
+        };
+        let mut total = 0;
+        let mut previous = first;
+        for guest in guests {
+            total += self.pairs[previous][guest];
+            previous = guest;
+        }
+        total + self.pairs[previous][first]
+    }
+
+    /// Checks if the guests are seated next to each other at the same table.
+    fn is_adjacent(&self, guest1: usize, guest2: usize) -> bool {
+        let Some(table) = self.tables.iter().position(|seats| {
+            self.seats[seats.clone()].contains(&Some(guest1))
+                && self.seats[seats.clone()].contains(&Some(guest2))
+        }) else {
+            return false;
+        };
+        let guests: Vec<usize> = self.table_guests(table).collect();
+        (0..guests.len()).any(|i| {
+            let next = guests[(i + 1) % guests.len()];
+            (guests[i] == guest1 && next == guest2) || (guests[i] == guest2 && next == guest1)
+        })
+    }
+
+    /// Returns the number of seating constraints not met by the assignment.
+    fn count_violations(&self) -> usize {
+        let together = (self.together.iter())
+            .filter(|&&(guest1, guest2)| !self.is_adjacent(guest1, guest2))
+            .count();
+        let apart = (self.apart.iter())
+            .filter(|&&(guest1, guest2)| self.is_adjacent(guest1, guest2))
+            .count();
+        together + apart
+    }
+}
+
+/// Returns the names of the guests in name order, including guests only named as the neighbours
+/// of other guests.
+fn guest_list(edges: &HashMap<String, HashMap<String, i64>>) -> Vec<&String> {
+    edges
+        .keys()
+        .chain(edges.values().flat_map(|to_guests| to_guests.keys()))
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// Returns the total change in happiness of the guests seated around a table in the given order.
+fn table_happiness(edges: &HashMap<String, HashMap<String, i64>>, table: &[&String]) -> i64 {
+    (0..table.len())
+        .map(|i| {
+            let next = table[(i + 1) % table.len()];
+            happiness(edges, table[i], next) + happiness(edges, next, table[i])
+        })
+        .sum()
+}
+
+/// Returns the number of constraints not met by the guests seated around the tables.
+fn count_violations(constraints: &SeatingConstraints, tables: &[Vec<&String>]) -> usize {
+    let is_adjacent = |guest1: &String, guest2: &String| {
+        tables.iter().any(|table| {
+            (0..table.len()).any(|i| {
+                let next = table[(i + 1) % table.len()];
+                (table[i] == guest1 && next == guest2) || (table[i] == guest2 && next == guest1)
+            })
+        })
+    };
+    let together = (constraints.together.iter())
+        .filter(|(guest1, guest2)| !is_adjacent(guest1, guest2))
+        .count();
+    let apart = (constraints.apart.iter())
+        .filter(|(guest1, guest2)| is_adjacent(guest1, guest2))
+        .count();
+    together + apart
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 13 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day13_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(664, solution);
+    }
+
+    /// Tests the Day 13 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day13_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(640, solution);
+    }
+
+    /// Tests that the best seating plan is returned with the change in happiness of each guest.
+    #[test]
+    fn test_day13_find_best_seating() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let plan = find_best_seating(&edges, &SeatingConstraints::default()).unwrap();
+        assert_eq!(
+            "Alice (+52), Bob (+76), Carol (+115), David (+87) = 330",
+            plan.to_string()
+        );
+        assert_eq!(vec![52, 76, 115, 87], plan.contributions);
+        let plan = find_best_seating(&edges, &SeatingConstraints::default().with_host("Carol"));
+        let expected = ["Carol", "Bob", "Alice", "David"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(Some(expected), plan.map(|plan| plan.guests));
+    }
+
+    /// Tests that seating plans keep guests together or apart as required.
+    #[test]
+    fn test_day13_seating_constraints() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let constraints = SeatingConstraints::default().with_apart("Bob", "Alice");
+        let plan = find_best_seating(&edges, &constraints).unwrap();
+        assert_eq!(
+            "Alice (-81), Carol (-2), Bob (-70), David (+39) = -114",
+            plan.to_string()
+        );
+        let constraints = SeatingConstraints::default().with_together("Alice", "Carol");
+        let plan = find_best_seating(&edges, &constraints).unwrap();
+        let expected = ["Alice", "Bob", "David", "Carol"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(expected, plan.guests);
+        assert_eq!(22, plan.total);
+        // Constraints must name guests on the list and be possible to meet
+        let constraints = SeatingConstraints::default().with_host("Eve");
+        assert_eq!(None, find_best_seating(&edges, &constraints));
+        let constraints = SeatingConstraints::default()
+            .with_together("Alice", "Bob")
+            .with_together("Alice", "Carol")
+            .with_together("Alice", "David");
+        assert_eq!(None, find_best_seating(&edges, &constraints));
+    }
+
+    /// Tests that guests are split across tables to maximise the total change in happiness.
+    #[test]
+    fn test_day13_table_seating() {
+        let edges = parse_input(EXAMPLES[0].input).unwrap();
+        let constraints = SeatingConstraints::default();
+        let seating = find_table_seating(&edges, &[4], &constraints).unwrap();
+        assert_eq!(330, seating.total);
+        // Guests at a table for two sit next to each other on both sides
+        let seating = find_table_seating(&edges, &[2, 2], &constraints).unwrap();
+        assert_eq!(
+            "Table 1: Alice (+108), Bob (+166) = 274\n\
+             Table 2: Carol (+110), David (+82) = 192\n\
+             Total = 466",
+            seating.to_string()
+        );
+        let constraints = constraints.with_host("David").with_apart("Alice", "Bob");
+        let seating = find_table_seating(&edges, &[3, 2], &constraints).unwrap();
+        assert_eq!("David", seating.tables[0].guests[0]);
+        assert_eq!(find_exact_table_seating(&edges, &[3], &constraints), None);
+        assert_eq!(find_table_seating(&edges, &[1, 1, 1], &constraints), None);
+    }
+
+    /// Tests that the exact search refuses guest lists over its limit, which are seated by
+    /// simulated annealing instead.
+    #[test]
+    fn test_day13_exact_table_seating_limit() {
+        let edges = generate_guests(EXACT_TABLE_SEATING_LIMIT + 1, |i, j| (i * j) as i64);
+        let constraints = SeatingConstraints::default();
+        assert_eq!(
+            None,
+            find_exact_table_seating(&edges, &[6, 6], &constraints)
+        );
+        let seating = find_table_seating(&edges, &[6, 6], &constraints).unwrap();
+        let seated: usize = seating.tables.iter().map(|plan| plan.guests.len()).sum();
+        assert_eq!(EXACT_TABLE_SEATING_LIMIT + 1, seated);
+    }
+
+    /// Tests that simulated annealing agrees with the exact search on the actual guest list, and
+    /// meets the constraints on a guest list too large for the exact search.
+    #[test]
+    fn test_day13_annealed_table_seating() {
+        let edges = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let constraints = SeatingConstraints::default().with_together("Alice", "Bob");
+        let exact = find_exact_table_seating(&edges, &[3, 3, 3], &constraints).unwrap();
+        let annealed =
+            find_annealed_table_seating(&edges, &[3, 3, 3], &constraints, 1, 200_000).unwrap();
+        assert_eq!(exact.total, annealed.total);
+        let edges = generate_guests(24, |i, j| ((i * 37 + j * 91 + i * j * 13) % 41) as i64 - 20);
+        let constraints = SeatingConstraints::default()
+            .with_host("G05")
+            .with_together("G01", "G02")
+            .with_apart("G03", "G04");
+        let seating = find_table_seating(&edges, &[10, 10, 6], &constraints).unwrap();
+        let seated: Vec<usize> = seating
+            .tables
+            .iter()
+            .map(|plan| plan.guests.len())
+            .collect();
+        assert_eq!(24, seated.iter().sum::<usize>());
+        assert!(seated[0] <= 10 && seated[1] <= 10 && seated[2] <= 6);
+        assert_eq!("G05", seating.tables[0].guests[0]);
+        let tables: Vec<Vec<&String>> = seating
+            .tables
+            .iter()
+            .map(|plan| plan.guests.iter().collect())
+            .collect();
+        assert_eq!(0, count_violations(&constraints, &tables));
+        // Better than leaving the guests seated in name order
+        let guests = guest_list(&edges);
+        let start = SeatAssignment::new(&edges, &guests, &[10, 10, 6], &constraints);
+        let start_total: i64 = (0..3).map(|table| start.table_happiness(table)).sum();
+        assert!(seating.total > start_total);
+    }
+
+    /// Generates a guest list with the given number of guests, using the given function of the guest
+    /// indices to determine the change in happiness of the first guest from sitting next to the
+    /// second.
+    fn generate_guests(
+        guest_count: usize,
+        happiness: impl Fn(usize, usize) -> i64,
+    ) -> HashMap<String, HashMap<String, i64>> {
+        (0..guest_count)
+            .map(|i| {
+                let to_guests = (0..guest_count)
+                    .filter(|&j| j != i)
+                    .map(|j| (format!("G{:02}", j), happiness(i, j)))
+                    .collect();
+                (format!("G{:02}", i), to_guests)
+            })
+            .collect()
+    }
+
+    /// Tests the Day 13 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day13_examples() {
+        assert_examples::<Day13>();
+    }
+}
@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+use std::fs;
+
+use fancy_regex::Regex;
+
+use crate::example::Example;
+use crate::solution::{Param, Params, Solution};
+use crate::utils::bespoke::Reindeer;
+use crate::utils::{parse_capture, ParseError};
+
+pub const PROBLEM_NAME: &str = "Reindeer Olympics";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day14.txt";
+pub const PROBLEM_DAY: u64 = 14;
+
+const RACE_DURATION: Param = Param {
+    name: "duration",
+    default: 2503,
+    description: "Duration of the race in seconds",
+    parse: None,
+};
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+     Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.\n",
+    Some("1120"),
+    Some("689"),
+)
+.with_params(&[("duration", 1000)])];
+
+/// Solution for AOC 2015 Day 14.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMS: &'static [Param] = &[RACE_DURATION];
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Reindeer>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Part1 {
+        find_furthest_distance(input, params.get(&RACE_DURATION))
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Part2 {
+        find_winning_points(input, params.get(&RACE_DURATION))
+    }
+}
+
+/// Processes the AOC 2015 Day 14 input file into the format required by the solver functions.
+/// Returned value is vector of reindeers as specified in the input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<Reindeer>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 14 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Reindeer>, ParseError> {
+    let mut reindeers: Vec<Reindeer> = vec![];
+    let regex_line = Regex::new(
+        r"^.* can fly (\d+) km/s for (\d+) seconds, but then must rest for (\d+) seconds.$",
+    )
+    .unwrap();
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Ok(Some(caps)) = regex_line.captures(line) {
+            let speed = parse_capture::<u64>(&caps, 1, i + 1, column, "speed")?;
+            let duration_travel = parse_capture::<u64>(&caps, 2, i + 1, column, "duration")?;
+            let duration_rest = parse_capture::<u64>(&caps, 3, i + 1, column, "duration")?;
+            reindeers.push(Reindeer::new(speed, duration_travel, duration_rest));
+        } else {
+            return Err(ParseError::new(i + 1, column, regex_line.as_str(), line));
+        }
+    }
+    Ok(reindeers)
+}
+
+/// Solves AOC 2015 Day 14 Part 1 // Determines the furthest distance travelled by a reindeer
+/// during the race.
+pub fn solve_part1(reindeers: &[Reindeer]) -> u64 {
+    find_furthest_distance(reindeers, RACE_DURATION.default)
+}
+
+/// Solves AOC 2015 Day 14 Part 2 // Determines the number of points held by the winning reindeer
+/// after the leading reindeer is awarded one point after each second in the race.
+pub fn solve_part2(reindeers: &[Reindeer]) -> u64 {
+    find_winning_points(reindeers, RACE_DURATION.default)
+}
+
+/// Determines the furthest distance travelled by a reindeer during a race of the given duration.
+fn find_furthest_distance(reindeers: &[Reindeer], duration: u64) -> u64 {
+    reindeers
+        .iter()
+        .map(|r| r.distance_travelled_in_period(duration))
+        .max()
+        .unwrap()
+}
+
+/// Determines the number of points held by the winning reindeer at the end of a race of the given
+/// duration.
+fn find_winning_points(reindeers: &[Reindeer], duration: u64) -> u64 {
+    let mut reindeers = reindeers.to_vec();
+    let mut points: Vec<u64> = vec![0; reindeers.len()];
+    for _ in 0..duration {
+        let mut max_i: Vec<usize> = vec![];
+        let mut max_distance = 0;
+        for (i, reindeer) in reindeers.iter_mut().enumerate() {
+            let dist = reindeer.advance_one_second();
+            // Check if a new maximum distance or tie has been found
+            match dist.cmp(&max_distance) {
+                Ordering::Less => (),
+                Ordering::Equal => max_i.push(i),
+                Ordering::Greater => {
+                    max_distance = dist;
+                    max_i = vec![i];
+                }
+            }
+        }
+        // Award points to the reindeer/s in the lead
+        for i in max_i {
+            points[i] += 1;
+        }
+    }
+    // Return the highest points total accrued by a reindeer during the race
+    points.into_iter().max().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 14 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day14_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(2640, solution);
+    }
+
+    /// Tests the Day 14 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day14_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(1102, solution);
+    }
+
+    /// Tests the Day 14 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day14_examples() {
+        assert_examples::<Day14>();
+    }
+}
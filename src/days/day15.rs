@@ -1,79 +1,89 @@
 use std::fs;
-use std::time::Instant;
 
 use fancy_regex::{Captures, Regex};
 
-use aoc2015::utils::bespoke::Ingredient;
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::bespoke::Ingredient;
+use crate::utils::{parse_capture, ParseError};
 
-const PROBLEM_NAME: &str = "Science for Hungry People";
-const PROBLEM_INPUT_FILE: &str = "./input/day15.txt";
-const PROBLEM_DAY: u64 = 15;
+pub const PROBLEM_NAME: &str = "Science for Hungry People";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day15.txt";
+pub const PROBLEM_DAY: u64 = 15;
 
 const LIMIT_TSP: i64 = 100;
 const TARGET_CALORIES: i64 = 500;
 
-/// Processes the AOC 2015 Day 15 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8\n\
+     Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3\n",
+    Some("62842880"),
+    Some("57600000"),
+)];
+
+/// Solution for AOC 2015 Day 15.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Ingredient>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 15 input file into the format required by the solver functions.
 /// Returned value is vector of ingredients described in the inpu t file.
-fn process_input_file(filename: &str) -> Vec<Ingredient> {
+pub fn process_input_file(filename: &str) -> Result<Vec<Ingredient>, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 15 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Ingredient>, ParseError> {
     let mut ingredients: Vec<Ingredient> = vec![];
     let regex_line = Regex::new(concat!(
         r#"^.*: capacity (-?\d+), durability (-?\d+), flavor (-?\d+), "#,
         r#"texture (-?\d+), calories (-?\d+)$"#,
     ))
     .unwrap();
-    for line in raw_input.lines() {
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
         if let Ok(Some(caps)) = regex_line.captures(line) {
-            ingredients.push(parse_captures_to_ingredient(&caps));
+            ingredients.push(parse_captures_to_ingredient(&caps, i + 1, column)?);
         } else {
-            panic!("Bad format input line! // {line}");
+            return Err(ParseError::new(i + 1, column, regex_line.as_str(), line));
         }
     }
-    ingredients
+    Ok(ingredients)
 }
 
 /// Solves AOC 2015 Day 15 Part 1 // Finds the total score of the higest-scoring cookie that can be
 /// made within the quantity limit.
-fn solve_part1(ingredients: &[Ingredient]) -> i64 {
+pub fn solve_part1(ingredients: &[Ingredient]) -> i64 {
     if let Some(score) = find_highest_cookie_score(ingredients, false) {
         score
     } else {
@@ -83,7 +93,7 @@ fn solve_part1(ingredients: &[Ingredient]) -> i64 {
 
 /// Solves AOC 2015 Day 15 Part 2 // Finds the total score of the highest-scoring cookie with the
 /// target calorie count that can be made from the ingredients.
-fn solve_part2(ingredients: &[Ingredient]) -> i64 {
+pub fn solve_part2(ingredients: &[Ingredient]) -> i64 {
     if let Some(score) = find_highest_cookie_score(ingredients, true) {
         score
     } else {
@@ -93,19 +103,25 @@ fn solve_part2(ingredients: &[Ingredient]) -> i64 {
 
 /// Parses the given regex captures into an ingredient. Assumption is that the captures result from
 /// regex matching the input file line format for an ingredient.
-fn parse_captures_to_ingredient(caps: &Captures) -> Ingredient {
-    let capacity = caps[1].parse::<i64>().unwrap();
-    let durability = caps[2].parse::<i64>().unwrap();
-    let flavour = caps[3].parse::<i64>().unwrap();
-    let texture = caps[4].parse::<i64>().unwrap();
-    let calories = caps[5].parse::<i64>().unwrap();
-    Ingredient::new(capacity, durability, flavour, texture, calories)
+fn parse_captures_to_ingredient(
+    caps: &Captures,
+    line: usize,
+    column: usize,
+) -> Result<Ingredient, ParseError> {
+    let capacity = parse_capture::<i64>(caps, 1, line, column, "capacity")?;
+    let durability = parse_capture::<i64>(caps, 2, line, column, "durability")?;
+    let flavour = parse_capture::<i64>(caps, 3, line, column, "flavour")?;
+    let texture = parse_capture::<i64>(caps, 4, line, column, "texture")?;
+    let calories = parse_capture::<i64>(caps, 5, line, column, "calories")?;
+    Ok(Ingredient::new(
+        capacity, durability, flavour, texture, calories,
+    ))
 }
 
 /// Finds the highest cookie score possible with the ingredients and quantity limit.
 fn find_highest_cookie_score(ingredients: &[Ingredient], check_calories: bool) -> Option<i64> {
     let mut max_score: Option<i64> = None;
-    find_highest_cookie_score_recursive(ingredients, &vec![], 0, check_calories, &mut max_score);
+    find_highest_cookie_score_recursive(ingredients, &[], 0, check_calories, &mut max_score);
     max_score
 }
 
@@ -113,7 +129,7 @@ fn find_highest_cookie_score(ingredients: &[Ingredient], check_calories: bool) -
 /// quantity limit. Calorie checking is optional.
 fn find_highest_cookie_score_recursive(
     ingredients: &[Ingredient],
-    quantities: &Vec<i64>,
+    quantities: &[i64],
     running_total: i64,
     check_calories: bool,
     max_score: &mut Option<i64>,
@@ -128,7 +144,7 @@ fn find_highest_cookie_score_recursive(
         if tsp + running_total > LIMIT_TSP {
             return;
         }
-        let mut quantities = quantities.clone();
+        let mut quantities = quantities.to_vec();
         quantities.push(tsp);
         find_highest_cookie_score_recursive(
             ingredients,
@@ -191,11 +207,12 @@ fn calculate_cookie_score(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 15 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day15_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(13882464, solution);
     }
@@ -203,8 +220,14 @@ mod test {
     /// Tests the Day 15 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day15_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(11171160, solution);
     }
+
+    /// Tests the Day 15 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day15_examples() {
+        assert_examples::<Day15>();
+    }
 }
@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 use std::fs;
-use std::time::Instant;
 
 use fancy_regex::Regex;
 use lazy_static::lazy_static;
 
-const PROBLEM_NAME: &str = "Aunt Sue";
-const PROBLEM_INPUT_FILE: &str = "./input/day16.txt";
-const PROBLEM_DAY: u64 = 16;
+use crate::solution::Solution;
+use crate::utils::{parse_capture, ParseError};
+
+pub const PROBLEM_NAME: &str = "Aunt Sue";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day16.txt";
+pub const PROBLEM_DAY: u64 = 16;
 
 lazy_static! {
     static ref AUNT_SUE_ITEMS: HashMap<Category, u64> = HashMap::from([
@@ -26,7 +28,7 @@ lazy_static! {
 
 /// Represents the different category of items that Aunt Sue can have.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-enum Category {
+pub enum Category {
     Children,
     Cats,
     Samoyeds,
@@ -58,46 +60,44 @@ impl Category {
     }
 }
 
-/// Processes the AOC 2015 Day 16 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Solution for AOC 2015 Day 16.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    type Input = Vec<HashMap<Category, u64>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 16 input file into the format required by the solver functions.
 /// Returned value is vector of hashmaps containing the categories and quantities for each of the
 /// Aunts Sue listed in the input file.
-fn process_input_file(filename: &str) -> Vec<HashMap<Category, u64>> {
+pub fn process_input_file(filename: &str) -> Result<Vec<HashMap<Category, u64>>, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 16 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<HashMap<Category, u64>>, ParseError> {
     let mut aunts: Vec<HashMap<Category, u64>> = vec![];
     let regex_line = Regex::new(concat!(
         r#"(children|cats|samoyeds|pomeranians|akitas|vizslas|goldfish|trees|cars|perfumes): (\d+), "#,
@@ -105,29 +105,30 @@ fn process_input_file(filename: &str) -> Vec<HashMap<Category, u64>> {
         r#"(children|cats|samoyeds|pomeranians|akitas|vizslas|goldfish|trees|cars|perfumes): (\d+)"#,
     ))
     .unwrap();
-    for line in raw_input.lines() {
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
         if let Ok(Some(caps)) = regex_line.captures(line) {
             let mut aunt_sue: HashMap<Category, u64> = HashMap::new();
-            for i in [1, 3, 5] {
-                let category = Category::from_string(&caps[i]).unwrap();
-                let quantity = caps[i + 1].parse::<u64>().unwrap();
+            for group in [1, 3, 5] {
+                let category = Category::from_string(&caps[group]).unwrap();
+                let quantity = parse_capture::<u64>(&caps, group + 1, i + 1, column, "quantity")?;
                 aunt_sue.insert(category, quantity);
             }
             aunts.push(aunt_sue);
         } else {
-            panic!("Bad format input line! // {line}");
+            return Err(ParseError::new(i + 1, column, regex_line.as_str(), line));
         }
     }
-    aunts
+    Ok(aunts)
 }
 
 /// Solves AOC 2015 Day 16 Part 1 // Determines the number of the Aunt Sue that gave the gift to the
 /// protagonist.
-fn solve_part1(aunts: &[HashMap<Category, u64>]) -> usize {
+pub fn solve_part1(aunts: &[HashMap<Category, u64>]) -> usize {
     for (i, candidate) in aunts.iter().enumerate() {
         if check_aunt_sue(candidate, false) {
             return i + 1;
@@ -138,7 +139,7 @@ fn solve_part1(aunts: &[HashMap<Category, u64>]) -> usize {
 
 /// Solves AOC 2015 Day 16 Part 2 // Determines the number of the Aunt Sue that gave the gift to the
 /// protagonist with range checks on some Aunt Sue item quantities.
-fn solve_part2(aunts: &[HashMap<Category, u64>]) -> usize {
+pub fn solve_part2(aunts: &[HashMap<Category, u64>]) -> usize {
     for (i, candidate) in aunts.iter().enumerate() {
         if check_aunt_sue(candidate, true) {
             return i + 1;
@@ -177,7 +178,7 @@ mod test {
     /// Tests the Day 16 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day16_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(373, solution);
     }
@@ -185,7 +186,7 @@ mod test {
     /// Tests the Day 16 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day16_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(260, solution);
     }
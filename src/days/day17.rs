@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::{Param, Params, Solution};
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "No Such Thing as Too Much";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day17.txt";
+pub const PROBLEM_DAY: u64 = 17;
+
+const TARGET_TOTAL: Param = Param {
+    name: "litres",
+    default: 150,
+    description: "Total volume of eggnog to be stored in litres",
+    parse: None,
+};
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "20\n\
+     15\n\
+     10\n\
+     5\n\
+     5\n",
+    Some("4"),
+    Some("3"),
+)
+.with_params(&[("litres", 25)])];
+
+/// Solution for AOC 2015 Day 17.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMS: &'static [Param] = &[TARGET_TOTAL];
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Part1 {
+        let (total, _) = find_subsets_adding_to_total(input, params.get(&TARGET_TOTAL));
+        total
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Part2 {
+        let (_, min_terms_count) = find_subsets_adding_to_total(input, params.get(&TARGET_TOTAL));
+        min_terms_count
+    }
+}
+
+/// Processes the AOC 2015 Day 17 input file into the format required by the solver functions.
+/// Returned value is vector of values given as lines in the problem input file.
+pub fn process_input_file(filename: &str) -> Result<Vec<u64>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 17 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut values: Vec<u64> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<u64>() {
+            Ok(value) => values.push(value),
+            Err(_) => return Err(ParseError::new(i + 1, column, "container size", line)),
+        }
+    }
+    Ok(values)
+}
+
+/// Solves AOC 2015 Day 17 Part 1 // Finds the total number of ways to reach the target total of 150
+/// from the given values.
+pub fn solve_part1(values: &[u64]) -> u64 {
+    let (total, _) = find_subsets_adding_to_total(values, TARGET_TOTAL.default);
+    total
+}
+
+/// Solves AOC 2015 Day 17 Part 2 // Finds the number of ways that the target total can be reached
+/// using the minimum possible number of terms from the given values.
+pub fn solve_part2(values: &[u64]) -> u64 {
+    let (_, min_terms_count) = find_subsets_adding_to_total(values, TARGET_TOTAL.default);
+    min_terms_count
+}
+
+/// Finds the total number of subsets of the given values that add up to the target total, and the
+/// number of ways to reach the target total with the minimum number of terms.
+fn find_subsets_adding_to_total(values: &[u64], target: u64) -> (u64, u64) {
+    let mut container_counts: HashMap<u64, u64> = HashMap::new();
+    find_subsets_adding_to_total_recursive(values, target, 0, 0, 0, &mut container_counts);
+    let total: u64 = container_counts.values().sum();
+    let min_terms: u64 = *container_counts.keys().min().unwrap();
+    let min_terms_count: u64 = *container_counts.get(&min_terms).unwrap();
+    (total, min_terms_count)
+}
+
+/// Recursive helper function used to find subsets of the given values vector that add up to the
+/// target total.
+fn find_subsets_adding_to_total_recursive(
+    values: &[u64],
+    target: u64,
+    i: usize,
+    num_terms: u64,
+    running_total: u64,
+    container_counts: &mut HashMap<u64, u64>,
+) {
+    match running_total.cmp(&target) {
+        Ordering::Less => {
+            // Stop if all values have been considered without reaching the target total exactly
+            if i >= values.len() {
+                return;
+            }
+            // Include the current term
+            find_subsets_adding_to_total_recursive(
+                values,
+                target,
+                i + 1,
+                num_terms + 1,
+                running_total + values[i],
+                container_counts,
+            );
+            // Exclude the current term
+            find_subsets_adding_to_total_recursive(
+                values,
+                target,
+                i + 1,
+                num_terms,
+                running_total,
+                container_counts,
+            );
+        }
+        Ordering::Equal => {
+            if let Entry::Vacant(e) = container_counts.entry(num_terms) {
+                e.insert(1);
+            } else {
+                *container_counts.get_mut(&num_terms).unwrap() += 1;
+            }
+        }
+        Ordering::Greater => (),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 17 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day17_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(1638, solution);
+    }
+
+    /// Tests the Day 17 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day17_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(17, solution);
+    }
+
+    /// Tests the Day 17 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day17_examples() {
+        assert_examples::<Day17>();
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::fs;
+
+use aoc_utils::cartography::Point2D;
+
+use crate::example::Example;
+use crate::solution::{Param, Params, Solution};
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Like a GIF For Your Yard";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day18.txt";
+pub const PROBLEM_DAY: u64 = 18;
+
+const STEPS: Param = Param {
+    name: "steps",
+    default: 100,
+    description: "Number of animation steps to simulate",
+    parse: None,
+};
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        ".#.#.#\n\
+         ...##.\n\
+         #....#\n\
+         ..#...\n\
+         #.#..#\n\
+         ####..\n",
+        Some("4"),
+        None,
+    )
+    .with_params(&[("steps", 4)]),
+    Example::new(
+        ".#.#.#\n\
+         ...##.\n\
+         #....#\n\
+         ..#...\n\
+         #.#..#\n\
+         ####..\n",
+        None,
+        Some("17"),
+    )
+    .with_params(&[("steps", 5)]),
+];
+
+/// Solution for AOC 2015 Day 18.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMS: &'static [Param] = &[STEPS];
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = HashMap<Point2D, bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Part1 {
+        count_lights_on(input, params.get(&STEPS), false)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Part2 {
+        count_lights_on(input, params.get(&STEPS), true)
+    }
+}
+
+/// Processes the AOC 2015 Day 18 input file into the format required by the solver functions.
+/// Returned value is hashmap of lightgrid locations and initial light state (true: on, false: off).
+pub fn process_input_file(filename: &str) -> Result<HashMap<Point2D, bool>, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 18 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<HashMap<Point2D, bool>, ParseError> {
+    let mut lightgrid: HashMap<Point2D, bool> = HashMap::new();
+    for (y, row) in raw_input.trim().lines().enumerate() {
+        for (x, elem) in row.chars().enumerate() {
+            let loc = Point2D::new(x as i64, y as i64);
+            let state = match elem {
+                '#' => true,
+                '.' => false,
+                _ => {
+                    let found = elem.to_string();
+                    return Err(ParseError::new(y + 1, x + 1, "'#' or '.'", &found));
+                }
+            };
+            lightgrid.insert(loc, state);
+        }
+    }
+    Ok(lightgrid)
+}
+
+/// Solves AOC 2015 Day 18 Part 1 // Determines the number of lights that are left on after 100
+/// steps from the initial configuration of the lightgrid.
+pub fn solve_part1(lightgrid: &HashMap<Point2D, bool>) -> usize {
+    count_lights_on(lightgrid, STEPS.default, false)
+}
+
+/// Solves AOC 2015 Day 18 Part 2 // Determines the number of lights that are left on after 100
+/// steps from the initial configuration of the lightgrid, with the four corner lights stuck in the
+/// "on" position.
+pub fn solve_part2(lightgrid: &HashMap<Point2D, bool>) -> usize {
+    count_lights_on(lightgrid, STEPS.default, true)
+}
+
+/// Determines the number of lights that are left on after the given number of steps from the
+/// initial configuration of the lightgrid, optionally with the four corner lights stuck on.
+fn count_lights_on(lightgrid: &HashMap<Point2D, bool>, steps: u64, corners_stuck: bool) -> usize {
+    let stuck_on = if corners_stuck {
+        find_corner_lights(lightgrid)
+    } else {
+        vec![]
+    };
+    let new_lightgrid = simulate_lightgrid(lightgrid, steps, &stuck_on);
+    new_lightgrid.values().filter(|elem| **elem).count()
+}
+
+/// Determines the locations of the four corner lights in the lightgrid.
+fn find_corner_lights(lightgrid: &HashMap<Point2D, bool>) -> Vec<Point2D> {
+    let width = (0..)
+        .take_while(|x| lightgrid.contains_key(&Point2D::new(*x, 0)))
+        .count() as i64;
+    let height = (0..)
+        .take_while(|y| lightgrid.contains_key(&Point2D::new(0, *y)))
+        .count() as i64;
+    vec![
+        Point2D::new(0, 0),
+        Point2D::new(width - 1, 0),
+        Point2D::new(0, height - 1),
+        Point2D::new(width - 1, height - 1),
+    ]
+}
+
+/// Simulates the given number of steps from the initial lightgrid state and returns the resulting
+/// lightgrid.
+fn simulate_lightgrid(
+    lightgrid: &HashMap<Point2D, bool>,
+    steps: u64,
+    stuck_on: &[Point2D],
+) -> HashMap<Point2D, bool> {
+    // Initialise the lightgrid with the stuck lights set to on
+    let mut old_lightgrid = lightgrid.clone();
+    for stuck_loc in stuck_on {
+        old_lightgrid.insert(*stuck_loc, true);
+    }
+    for _ in 0..steps {
+        // Initialise the new lightgrid with the stuck lights set to on
+        let mut new_lightgrid: HashMap<Point2D, bool> = HashMap::new();
+        for stuck_loc in stuck_on {
+            new_lightgrid.insert(*stuck_loc, true);
+        }
+        for loc in old_lightgrid.keys() {
+            // Skip the stuck lights since they have already been added to the new lightgrid
+            if new_lightgrid.contains_key(loc) {
+                continue;
+            }
+            let new_state = determine_new_state(loc, &old_lightgrid);
+            new_lightgrid.insert(*loc, new_state);
+        }
+        // Update the old lightgrid for the next step
+        old_lightgrid = new_lightgrid;
+    }
+    old_lightgrid
+}
+
+/// Determines the new state for the light at the given location in the next step lightgrid.
+fn determine_new_state(loc: &Point2D, old_lightgrid: &HashMap<Point2D, bool>) -> bool {
+    // Count the number of surrounding lights that are on
+    let mut count_on = 0;
+    for sloc in loc.get_surrounding_points() {
+        if *old_lightgrid.get(&sloc).unwrap_or(&false) {
+            count_on += 1;
+        }
+    }
+    // Determine the new state based on current state and neighbour-on count
+    let new_state = match old_lightgrid.get(loc).unwrap() {
+        true => count_on == 2 || count_on == 3,
+        false => count_on == 3,
+    };
+    new_state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 18 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day18_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(821, solution);
+    }
+
+    /// Tests the Day 18 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day18_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(886, solution);
+    }
+
+    /// Tests the Day 18 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day18_examples() {
+        assert_examples::<Day18>();
+    }
+}
@@ -1,81 +1,124 @@
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::time::Instant;
 
-const PROBLEM_NAME: &str = "Medicine for Rudolph";
-const PROBLEM_INPUT_FILE: &str = "./input/day19.txt";
-const PROBLEM_DAY: u64 = 19;
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Medicine for Rudolph";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day19.txt";
+pub const PROBLEM_DAY: u64 = 19;
 
 /// Type definition to simplify signature of input file parser and solver functions.
 type ProblemInput = (HashMap<String, Vec<String>>, String);
 
-/// Processes the AOC 2015 Day 19 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "e => H\n\
+         e => O\n\
+         H => HO\n\
+         H => OH\n\
+         O => HH\n\
+         \n\
+         HOH\n",
+        Some("4"),
+        Some("3"),
+    ),
+    Example::new(
+        "e => H\n\
+         e => O\n\
+         H => HO\n\
+         H => OH\n\
+         O => HH\n\
+         \n\
+         HOHOHO\n",
+        Some("7"),
+        Some("6"),
+    ),
+];
+
+/// Solution for AOC 2015 Day 19.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = ProblemInput;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 19 input file into the format required by the solver functions.
 /// Returned value is tuple containing: hashmap of input molecules mapped to possible replacement
 /// molecures, and the target molecule.
-fn process_input_file(filename: &str) -> ProblemInput {
+pub fn process_input_file(filename: &str) -> Result<ProblemInput, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 19 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<ProblemInput, ParseError> {
     let mut replacements: HashMap<String, Vec<String>> = HashMap::new();
-    let mut split = raw_input.trim().split("\n\n");
-    // Process the replacement options
-    for line in split.next().unwrap().lines() {
+    let mut med_molecule: Option<String> = None;
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
-        let elems = line.split(" => ").collect::<Vec<&str>>();
-        if let Entry::Vacant(e) = replacements.entry(elems[0].to_string()) {
-            e.insert(vec![elems[1].to_string()]);
+        // Medicine molecule is the last non-empty line of the input
+        if med_molecule.is_some() {
+            return Err(ParseError::new(i + 1, column, "end of input", line));
+        }
+        // Process the replacement options
+        if let Some((from, to)) = line.split_once(" => ") {
+            if let Entry::Vacant(e) = replacements.entry(from.to_string()) {
+                e.insert(vec![to.to_string()]);
+            } else {
+                replacements.get_mut(from).unwrap().push(to.to_string());
+            }
         } else {
-            replacements
-                .get_mut(elems[0])
-                .unwrap()
-                .push(elems[1].to_string());
+            // Extract the medicine molecule
+            med_molecule = Some(line.to_string());
+        }
+    }
+    match med_molecule {
+        Some(med_molecule) => Ok((replacements, med_molecule)),
+        None => {
+            let expected = "medicine molecule";
+            Err(ParseError::at_offset(
+                raw_input,
+                raw_input.len(),
+                expected,
+                "end of input",
+            ))
         }
     }
-    // Extract the medicine molecule
-    let med_molecule = split.next().unwrap().to_string();
-    (replacements, med_molecule)
 }
 
 /// Solves AOC 2015 Day 19 Part 1 // Determines the number of distinct molecules that can be created
 /// after all the possible ways to conduct one replacement are tried on the medicine molecule.
-fn solve_part1(input: &ProblemInput) -> usize {
+pub fn solve_part1(input: &ProblemInput) -> usize {
     let (replacements, med_molecule) = input;
     let mut observed: HashSet<String> = HashSet::new();
     for (input_str, outputs) in replacements.iter() {
@@ -104,7 +147,7 @@ fn solve_part1(input: &ProblemInput) -> usize {
 
 /// Solves AOC 2015 Day 19 Part 2 // Determines the minimum number of steps required to generate the
 /// medicine molecule from a single electron "e".
-fn solve_part2(input: &ProblemInput) -> u64 {
+pub fn solve_part2(input: &ProblemInput) -> u64 {
     let (replacements, med_molecule) = input;
     let mut process_molecule = med_molecule.to_string();
     let rev_reps = reverse_replacements_map(replacements);
@@ -171,11 +214,12 @@ fn reverse_replacements_map(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 19 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day19_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(518, solution);
     }
@@ -183,8 +227,14 @@ mod test {
     /// Tests the Day 19 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day19_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(200, solution);
     }
+
+    /// Tests the Day 19 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day19_examples() {
+        assert_examples::<Day19>();
+    }
 }
@@ -0,0 +1,122 @@
+use std::fs;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Infinite Elves and Infinite Houses";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day20.txt";
+pub const PROBLEM_DAY: u64 = 20;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new("70", Some("4"), None),
+    Example::new("120", Some("6"), None),
+    Example::new("150", Some("8"), None),
+];
+
+/// Solution for AOC 2015 Day 20.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = usize;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 20 input file into the format required by the solver functions.
+/// Returned value is integer value given in the input file.
+pub fn process_input_file(filename: &str) -> Result<usize, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 20 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<usize, ParseError> {
+    let offset = raw_input.len() - raw_input.trim_start().len();
+    raw_input.trim().parse::<usize>().map_err(|_| {
+        ParseError::at_offset(raw_input, offset, "number of presents", raw_input.trim())
+    })
+}
+
+/// Solves AOC 2015 Day 20 Part 1 // Determines the lowest number house to get at least as many
+/// presents as the target value. (elves delivering 10 presents to each house that is a multiple
+/// of the elf number).
+pub fn solve_part1(target: &usize) -> usize {
+    let target = *target;
+    let mut houses: Vec<usize> = vec![0; target];
+    for elf in 1..=target {
+        for i in (elf..=target).step_by(elf) {
+            houses[i - 1] += elf * 10;
+            if i == elf && houses[i - 1] >= target {
+                return i;
+            }
+        }
+    }
+    panic!("Should not get here!");
+}
+
+/// Solves AOC 2015 Day 20 Part 2 // Determines the lowest number house to get at least as many
+/// presents as the target value, with each elf visited 50 houses (including their starting house)
+/// and delivering 11 presents to each house.
+pub fn solve_part2(target: &usize) -> usize {
+    let target = *target;
+    let mut houses: Vec<usize> = vec![0; target];
+    for elf in 1..target {
+        for i in (elf..=target).step_by(elf).take(50) {
+            houses[i - 1] += elf * 11;
+            if i == elf && houses[i - 1] >= target {
+                return i;
+            }
+        }
+    }
+    panic!("Should not get here!");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 20 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day20_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(831600, solution);
+    }
+
+    /// Tests the Day 20 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day20_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(884520, solution);
+    }
+
+    /// Tests the Day 20 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day20_examples() {
+        assert_examples::<Day20>();
+    }
+}
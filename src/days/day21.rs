@@ -1,15 +1,16 @@
 use std::fs;
-use std::time::Instant;
 
 use fancy_regex::Regex;
 use itertools::{iproduct, Itertools};
 use lazy_static::lazy_static;
 
-use aoc2015::utils::bespoke::{RpgEntity, RpgItem};
+use crate::solution::Solution;
+use crate::utils::bespoke::{RpgEntity, RpgItem};
+use crate::utils::ParseError;
 
-const PROBLEM_NAME: &str = "RPG Simulator 20XX";
-const PROBLEM_INPUT_FILE: &str = "./input/day21.txt";
-const PROBLEM_DAY: u64 = 21;
+pub const PROBLEM_NAME: &str = "RPG Simulator 20XX";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day21.txt";
+pub const PROBLEM_DAY: u64 = 21;
 
 const PLAYER_START_HEALTH: i64 = 100;
 
@@ -41,59 +42,64 @@ lazy_static! {
     ];
 }
 
-/// Processes the AOC 2015 Day 21 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Solution for AOC 2015 Day 21.
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    type Input = RpgEntity;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 21 input file into the format required by the solver functions.
 /// Returned value is the RpgEntity representing the boss entity specified in the input file.
-fn process_input_file(filename: &str) -> RpgEntity {
+pub fn process_input_file(filename: &str) -> Result<RpgEntity, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 21 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<RpgEntity, ParseError> {
     let input_regex = Regex::new(r"Hit Points: (\d+)\nDamage: (\d+)\nArmor: (\d+)").unwrap();
-    if let Ok(Some(caps)) = input_regex.captures(&raw_input) {
-        let health = caps[1].parse::<i64>().unwrap();
-        let damage = caps[2].parse::<i64>().unwrap();
-        let armour = caps[3].parse::<i64>().unwrap();
+    if let Ok(Some(caps)) = input_regex.captures(raw_input) {
+        let parse_stat = |group: usize| {
+            let m = caps.get(group).unwrap();
+            m.as_str()
+                .parse::<i64>()
+                .map_err(|_| ParseError::at_offset(raw_input, m.start(), "integer", m.as_str()))
+        };
+        let health = parse_stat(1)?;
+        let damage = parse_stat(2)?;
+        let armour = parse_stat(3)?;
         let enemy = RpgEntity::new(health, damage, armour);
-        return enemy;
+        return Ok(enemy);
     }
-    panic!("Invalid input file format!");
+    let found = raw_input.lines().next().unwrap_or_default();
+    Err(ParseError::new(1, 1, input_regex.as_str(), found))
 }
 
 /// Solves AOC 2015 Day 21 Part 1 // Determines the least amount of gold the player can spend and
 /// still win the fight.
-fn solve_part1(enemy: &RpgEntity) -> i64 {
+pub fn solve_part1(enemy: &RpgEntity) -> i64 {
     let mut least_gold: Option<i64> = None;
     for (q_armour, q_rings) in iproduct!(0..=1, 0..=2) {
         for weapon_held in WEAPONS.iter() {
@@ -129,7 +135,7 @@ fn solve_part1(enemy: &RpgEntity) -> i64 {
 
 /// Solves AOC 2015 Day 21 Part 2 // Determines the most amount of gold the player can spend and
 /// still lose the fight.
-fn solve_part2(enemy: &RpgEntity) -> i64 {
+pub fn solve_part2(enemy: &RpgEntity) -> i64 {
     let mut most_gold: Option<i64> = None;
     for (q_armour, q_rings) in iproduct!(0..=1, 0..=2) {
         for weapon_held in WEAPONS.iter() {
@@ -170,7 +176,7 @@ mod test {
     /// Tests the Day 21 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day21_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(78, solution);
     }
@@ -178,7 +184,7 @@ mod test {
     /// Tests the Day 21 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day21_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(148, solution);
     }
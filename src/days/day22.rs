@@ -0,0 +1,255 @@
+use std::fs;
+
+use fancy_regex::Regex;
+use strum::IntoEnumIterator;
+
+use crate::example::Example;
+use crate::solution::{Param, Params, Solution};
+use crate::utils::bespoke::{MagicEntity, Spell};
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Wizard Simulator 20XX";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day22.txt";
+pub const PROBLEM_DAY: u64 = 22;
+
+// Player starting values
+const PLAYER_HEALTH: Param = Param {
+    name: "player_health",
+    default: 50,
+    description: "Starting hit points of the player",
+    parse: None,
+};
+const PLAYER_MANA: Param = Param {
+    name: "player_mana",
+    default: 500,
+    description: "Starting mana of the player",
+    parse: None,
+};
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "Hit Points: 13\n\
+         Damage: 8\n",
+        Some("226"),
+        None,
+    )
+    .with_params(&[("player_health", 10), ("player_mana", 250)]),
+    Example::new(
+        "Hit Points: 14\n\
+         Damage: 8\n",
+        Some("641"),
+        None,
+    )
+    .with_params(&[("player_health", 10), ("player_mana", 250)]),
+];
+
+/// Solution for AOC 2015 Day 22.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMS: &'static [Param] = &[PLAYER_HEALTH, PLAYER_MANA];
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = MagicEntity;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Part1 {
+        let player = new_player(params.get(&PLAYER_HEALTH), params.get(&PLAYER_MANA));
+        find_min_mana(&player, input, false)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Part2 {
+        let player = new_player(params.get(&PLAYER_HEALTH), params.get(&PLAYER_MANA));
+        find_min_mana(&player, input, true)
+    }
+}
+
+/// Processes the AOC 2015 Day 22 input file into the format required by the solver functions.
+/// Returned value is MagicEntity specified by the health and damage points in the input file
+/// (armour and mana are set to 0).
+pub fn process_input_file(filename: &str) -> Result<MagicEntity, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 22 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<MagicEntity, ParseError> {
+    let input_regex = Regex::new(r"Hit Points: (\d+)\nDamage: (\d+)").unwrap();
+    if let Ok(Some(caps)) = input_regex.captures(raw_input) {
+        let parse_stat = |group: usize| {
+            let m = caps.get(group).unwrap();
+            m.as_str()
+                .parse::<i64>()
+                .map_err(|_| ParseError::at_offset(raw_input, m.start(), "integer", m.as_str()))
+        };
+        let health = parse_stat(1)?;
+        let damage = parse_stat(2)?;
+        return Ok(MagicEntity::new(health, damage, 0, 0));
+    }
+    let found = raw_input.lines().next().unwrap_or_default();
+    Err(ParseError::new(1, 1, input_regex.as_str(), found))
+}
+
+/// Solves AOC 2015 Day 22 Part 1 // Determines the minimum amount of mana needed for the player to
+/// defeat the enemy in Wizard Simulator 20XX (easy mode).
+pub fn solve_part1(enemy: &MagicEntity) -> i64 {
+    let player = new_player(PLAYER_HEALTH.default, PLAYER_MANA.default);
+    find_min_mana(&player, enemy, false)
+}
+
+/// Solves AOC 2015 Day 22 Part 2 // Determines the minimum amount of mana needed for the player to
+/// defeat the enemy in Wizard Simulator 20XX (HARD mode).
+pub fn solve_part2(enemy: &MagicEntity) -> i64 {
+    let player = new_player(PLAYER_HEALTH.default, PLAYER_MANA.default);
+    find_min_mana(&player, enemy, true)
+}
+
+/// Creates the player with the given starting hit points and mana.
+fn new_player(health: u64, mana: u64) -> MagicEntity {
+    MagicEntity::new(health as i64, 0, 0, mana as i64)
+}
+
+/// Determines the minimum amount of mana needed for the player to defeat the enemy, panicking if
+/// the player is unable to defeat the enemy.
+fn find_min_mana(player: &MagicEntity, enemy: &MagicEntity, hard_mode: bool) -> i64 {
+    if let Some(min_mana) = conduct_fight(player, enemy, hard_mode) {
+        return min_mana;
+    }
+    match hard_mode {
+        false => panic!("Player was unable to defeat the enemy on easy mode!"),
+        true => panic!("Player was unable to defeat the enemy on HARD mode!"),
+    }
+}
+
+/// Conducts the fight between the player and the enemy. Returns an Option containing the minimum
+/// amount of mana needed by the player to defeat the enemy. Returned value is None if the player
+/// is unable to defeat the enemy.
+fn conduct_fight(player: &MagicEntity, enemy: &MagicEntity, hard_mode: bool) -> Option<i64> {
+    let mut min_mana: Option<i64> = None;
+    conduct_fight_recursive(player, enemy, hard_mode, &mut min_mana);
+    min_mana
+}
+
+fn conduct_fight_recursive(
+    player: &MagicEntity,
+    enemy: &MagicEntity,
+    hard_mode: bool,
+    min_mana: &mut Option<i64>,
+) {
+    for spell in Spell::iter() {
+        // Clone the player and the enemy
+        let mut player = player.clone();
+        let mut enemy = enemy.clone();
+        // Player turn
+        // Apply hard mode damage and check if player is dead
+        if hard_mode {
+            player.deal_damage(1, true);
+        }
+        if player.is_dead() {
+            return;
+        }
+        // Process player effects then check if enemy is dead
+        player.process_effects(&mut enemy);
+        if enemy.is_dead() {
+            update_min_mana(min_mana, &player);
+            return;
+        }
+        // Continue to next spell if player cannot cast the spell
+        if !player.can_cast(spell) {
+            return;
+        }
+        if player.is_effect_active(spell) {
+            continue;
+        }
+        // Cast the spell
+        match player.cast_spell(spell, &mut enemy, false) {
+            Ok(_) => (),
+            Err(_message) => continue,
+        }
+        // Check if the enemy is dead
+        if enemy.is_dead() {
+            update_min_mana(min_mana, &player);
+            return;
+        }
+        // Enemy turn
+        // Process player effects and check if enemy is dead
+        player.process_effects(&mut enemy);
+        if enemy.is_dead() {
+            update_min_mana(min_mana, &player);
+            return;
+        }
+        // Enemy deals damage to the player and check if player is dead, in which case a different
+        // spell may still allow the player to survive
+        player.deal_damage(enemy.damage(), false);
+        if player.is_dead() {
+            continue;
+        }
+        // Go to the next set of turns
+        conduct_fight_recursive(&player, &enemy, hard_mode, min_mana);
+    }
+}
+
+/// Updates the minimum mana value if the total mana spent by the player is a lower value.
+fn update_min_mana(min_mana: &mut Option<i64>, player: &MagicEntity) {
+    if min_mana.is_none() || min_mana.unwrap() > player.total_mana_spent() {
+        *min_mana = Some(player.total_mana_spent());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 22 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day22_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(1824, solution);
+    }
+
+    /// Tests the Day 22 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day22_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(1937, solution);
+    }
+
+    /// Tests that the search tries the remaining spells after a spell leads to the player being
+    /// killed by the enemy, rather than abandoning the turn. In the second worked example, the
+    /// spells tried first on some turns leave the player to be killed, while the winning spells
+    /// come later in the spell order.
+    #[test]
+    fn test_day22_player_death_tries_other_spells() {
+        let enemy = parse_input("Hit Points: 14\nDamage: 8\n").unwrap();
+        let player = new_player(10, 250);
+        assert_eq!(Some(641), conduct_fight(&player, &enemy, false));
+    }
+
+    /// Tests the Day 22 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day22_examples() {
+        assert_examples::<Day22>();
+    }
+}
@@ -0,0 +1,88 @@
+use std::fs;
+
+use crate::solution::Solution;
+use crate::utils::bespoke::SimpleComputer;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Opening the Turing Lock";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day23.txt";
+pub const PROBLEM_DAY: u64 = 23;
+
+/// Solution for AOC 2015 Day 23.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    type Input = SimpleComputer;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 23 input file into the format required by the solver functions.
+/// Returned value is SimpleComputer with instructions taken from input file and both registers set
+/// to 0.
+pub fn process_input_file(filename: &str) -> Result<SimpleComputer, ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 23 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<SimpleComputer, ParseError> {
+    SimpleComputer::new(raw_input, 0, 0)
+}
+
+/// Solves AOC 2015 Day 23 Part 1 // Returns the value held in register 'b' of the computer after
+/// executing the stored instructions.
+pub fn solve_part1(computer: &SimpleComputer) -> isize {
+    let mut computer = computer.clone();
+    computer.execute();
+    computer.register_b()
+}
+
+/// Solves AOC 2015 Day 23 Part 2 // Returns the value held in register 'b' of the computer after
+/// starting with register 'a' value of 1 and executing the stored instructions.
+pub fn solve_part2(computer: &SimpleComputer) -> isize {
+    let mut computer = computer.clone();
+    computer.set_register_a(1);
+    computer.execute();
+    computer.register_b()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the Day 23 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day23_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(307, solution);
+    }
+
+    /// Tests the Day 23 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day23_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part2(&input);
+        assert_eq!(160, solution);
+    }
+}
@@ -1,61 +1,87 @@
 use core::panic;
 use std::fs;
-use std::time::Instant;
-
-const PROBLEM_NAME: &str = "It Hangs in the Balance";
-const PROBLEM_INPUT_FILE: &str = "./input/day24.txt";
-const PROBLEM_DAY: u64 = 24;
-
-/// Processes the AOC 2015 Day 24 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "It Hangs in the Balance";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day24.txt";
+pub const PROBLEM_DAY: u64 = 24;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "1\n\
+     2\n\
+     3\n\
+     4\n\
+     5\n\
+     7\n\
+     8\n\
+     9\n\
+     10\n\
+     11\n",
+    Some("99"),
+    Some("44"),
+)];
+
+/// Solution for AOC 2015 Day 24.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u128>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 24 input file into the format required by the solver functions.
 /// Returned value is vector of values given in the input file.
-fn process_input_file(filename: &str) -> Vec<u128> {
+pub fn process_input_file(filename: &str) -> Result<Vec<u128>, ParseError> {
     // Read contents of problem input file
-    let raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
-    raw_input
-        .trim()
-        .lines()
-        .map(|value| value.parse::<u128>().unwrap())
-        .collect::<Vec<u128>>()
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 24 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<u128>, ParseError> {
+    let mut values: Vec<u128> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let column = line.len() - line.trim_start().len() + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<u128>() {
+            Ok(value) => values.push(value),
+            Err(_) => return Err(ParseError::new(i + 1, column, "package weight", line)),
+        }
+    }
+    Ok(values)
 }
 
 /// Solves AOC 2015 Day 24 Part 1 // Finds the minimum quantum entanglement value for the first
 /// compartment (the one with the fewest possible number of presents) where there is a total of
 /// three compartments.
-fn solve_part1(values: &[u128]) -> u128 {
+pub fn solve_part1(values: &[u128]) -> u128 {
     let target: u128 = values.iter().sum::<u128>() / 3;
     if let Some(qe) = find_compartment1_qe(values, target) {
         return qe;
@@ -66,7 +92,7 @@ fn solve_part1(values: &[u128]) -> u128 {
 /// Solves AOC 2015 Day 24 Part 2 // Finds the minimum quantum entanglement value for the first
 /// compartment (the one with the fewest possible number of presents) where these is a total of four
 /// compartments.
-fn solve_part2(values: &[u128]) -> u128 {
+pub fn solve_part2(values: &[u128]) -> u128 {
     let target: u128 = values.iter().sum::<u128>() / 4;
     if let Some(qe) = find_compartment1_qe(values, target) {
         return qe;
@@ -139,11 +165,12 @@ fn find_compartment1_qe_recursive(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 24 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day24_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part1(&input);
         assert_eq!(11846773891, solution);
     }
@@ -151,8 +178,14 @@ mod test {
     /// Tests the Day 24 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day24_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let solution = solve_part2(&input);
         assert_eq!(80393059, solution);
     }
+
+    /// Tests the Day 24 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day24_examples() {
+        assert_examples::<Day24>();
+    }
 }
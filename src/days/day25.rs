@@ -0,0 +1,137 @@
+use std::fs;
+
+use fancy_regex::Regex;
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "Let It Snow";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day25.txt";
+pub const PROBLEM_DAY: u64 = 25;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "To continue, please consult the code grid in the manual.  Enter the code at row 1, column 1.",
+        Some("20151125"),
+        None,
+    ),
+    Example::new(
+        "To continue, please consult the code grid in the manual.  Enter the code at row 2, column 1.",
+        Some("31916031"),
+        None,
+    ),
+    Example::new(
+        "To continue, please consult the code grid in the manual.  Enter the code at row 1, column 2.",
+        Some("18749137"),
+        None,
+    ),
+    Example::new(
+        "To continue, please consult the code grid in the manual.  Enter the code at row 4, column 2.",
+        Some("32451966"),
+        None,
+    ),
+    Example::new(
+        "To continue, please consult the code grid in the manual.  Enter the code at row 6, column 6.",
+        Some("27995004"),
+        None,
+    ),
+];
+
+/// Solution for AOC 2015 Day 25.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (u128, u128);
+    type Part1 = u128;
+    type Part2 = String;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
+}
+
+/// Processes the AOC 2015 Day 25 input file into the format required by the solver functions.
+/// Returned value is row and column number given in the input file.
+pub fn process_input_file(filename: &str) -> Result<(u128, u128), ParseError> {
+    // Read contents of problem input file
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
+    // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 25 input into the format required by the solver functions.
+pub fn parse_input(raw_input: &str) -> Result<(u128, u128), ParseError> {
+    let regex_input = Regex::new(r"row (\d+), column (\d+)").unwrap();
+    if let Ok(Some(caps)) = regex_input.captures(raw_input) {
+        let parse_coord = |group: usize| {
+            let m = caps.get(group).unwrap();
+            m.as_str()
+                .parse::<u128>()
+                .map_err(|_| ParseError::at_offset(raw_input, m.start(), "integer", m.as_str()))
+        };
+        let row = parse_coord(1)?;
+        let col = parse_coord(2)?;
+        return Ok((row, col));
+    }
+    let found = raw_input.lines().next().unwrap_or_default();
+    Err(ParseError::new(1, 1, regex_input.as_str(), found))
+}
+
+/// Solves AOC 2015 Day 25 Part 1 // Determines the code that needs to be given to the weather
+/// machine in order to activate it.
+pub fn solve_part1(location: &(u128, u128)) -> u128 {
+    let (row, col) = *location;
+    // First stage
+    let mut seq: u128 = (1..=col).sum();
+    for inc in col..(col + row - 1) {
+        seq += inc;
+    }
+    // Second stage
+    let mut code = 20151125;
+    for _ in 1..seq {
+        code = (code * 252533) % 33554393;
+    }
+    code
+}
+
+/// Solves AOC 2015 Day 25 Part 2 // There is no puzzle for Day 25 Part 2 - the final star is
+/// awarded for completing all other puzzles.
+pub fn solve_part2(_location: &(u128, u128)) -> String {
+    String::from("[CHRISTMAS IS SAVED!]")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::example::assert_examples;
+
+    /// Tests the Day 25 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day25_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
+        let solution = solve_part1(&input);
+        assert_eq!(19980801, solution);
+    }
+
+    /// Tests the Day 25 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day25_examples() {
+        assert_examples::<Day25>();
+    }
+}
@@ -0,0 +1,81 @@
+use crate::solution::DynSolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Registry of the solutions for all days of AOC 2015, in day order.
+pub static SOLUTIONS: [&dyn DynSolution; 25] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Returns the registered solution for the given day, if it exists.
+pub fn get_solution(day: u64) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS.iter().find(|s| s.day() == day).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Solution;
+
+    /// Tests that the solution registry holds each day exactly once and in day order.
+    #[test]
+    fn test_solutions_registry_order() {
+        let days = SOLUTIONS.iter().map(|s| s.day()).collect::<Vec<u64>>();
+        assert_eq!((1..=25).collect::<Vec<u64>>(), days);
+    }
+
+    /// Tests that a solution input can be parsed from an in-memory reader.
+    #[test]
+    fn test_solution_parse_reader() {
+        let input = day01::Day01::parse_reader("()())".as_bytes()).unwrap();
+        assert_eq!(5, day01::Day01::part2(&input));
+    }
+}
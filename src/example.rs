@@ -0,0 +1,126 @@
+use crate::runner::PartSelection;
+use crate::solution::{Answer, Params, Solution};
+use crate::utils::ParseError;
+use crate::verify::Verdict;
+
+/// Represents a worked example from a problem statement, being a sample input along with the
+/// expected answers. Expected answers are None for parts that the example does not cover. The
+/// solver parameter values are used instead of the parameter defaults when solving the example.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub params: &'static [(&'static str, u64)],
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    /// Creates a new Example with the given sample input and expected answers.
+    pub const fn new(
+        input: &'static str,
+        part1: Option<&'static str>,
+        part2: Option<&'static str>,
+    ) -> Example {
+        Example {
+            input,
+            params: &[],
+            part1,
+            part2,
+        }
+    }
+
+    /// Returns the Example with the given solver parameter values used when solving it.
+    pub const fn with_params(mut self, params: &'static [(&'static str, u64)]) -> Example {
+        self.params = params;
+        self
+    }
+
+    /// Returns a short description of the example input, being its first line (truncated if
+    /// needed) along with any solver parameter values.
+    pub fn summary(&self) -> String {
+        let first_line = self.input.lines().next().unwrap_or_default();
+        let mut summary = if first_line.chars().count() > 40 {
+            format!("{}...", first_line.chars().take(37).collect::<String>())
+        } else {
+            first_line.to_string()
+        };
+        if self.input.trim_end().contains('\n') {
+            summary.push_str(" [...]");
+        }
+        if !self.params.is_empty() {
+            let params = self
+                .params
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<String>>();
+            summary.push_str(&format!(" ({})", params.join(", ")));
+        }
+        summary
+    }
+}
+
+/// Holds the answers and verdicts for the parts of a worked example that were solved.
+pub struct ExampleResult {
+    pub part1: Option<(Answer, Verdict)>,
+    pub part2: Option<(Answer, Verdict)>,
+}
+
+impl ExampleResult {
+    /// Checks if the answers for all of the solved parts matched the expected answers.
+    pub fn is_pass(&self) -> bool {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .flatten()
+            .all(|(_, verdict)| *verdict == Verdict::Pass)
+    }
+}
+
+/// Solves the selected parts of the worked example that have an expected answer, and checks the
+/// answers against the expected answers.
+pub fn check_example<S: Solution>(
+    example: &Example,
+    parts: PartSelection,
+) -> Result<ExampleResult, ParseError> {
+    let params = Params::from_pairs(example.params);
+    let input = S::parse_with(example.input, &params)?;
+    let part1 = match example.part1 {
+        Some(expected) if parts.includes_part1() => {
+            let answer: Answer = S::part1_with(&input, &params).into();
+            let verdict = Verdict::check(&answer, Some(expected));
+            Some((answer, verdict))
+        }
+        _ => None,
+    };
+    let part2 = match example.part2 {
+        Some(expected) if parts.includes_part2() => {
+            let answer: Answer = S::part2_with(&input, &params).into();
+            let verdict = Verdict::check(&answer, Some(expected));
+            Some((answer, verdict))
+        }
+        _ => None,
+    };
+    Ok(ExampleResult { part1, part2 })
+}
+
+/// Checks all of the worked examples for the given solution, panicking with the details of any
+/// example that could not be parsed or did not produce the expected answers.
+pub fn assert_examples<S: Solution>() {
+    for (i, example) in S::EXAMPLES.iter().enumerate() {
+        let result = match check_example::<S>(example, PartSelection::Both) {
+            Ok(result) => result,
+            Err(err) => panic!("Day {} example {} failed to parse: {}", S::DAY, i + 1, err),
+        };
+        for (part, outcome) in [(1, &result.part1), (2, &result.part2)] {
+            if let Some((answer, Verdict::Fail { expected })) = outcome {
+                panic!(
+                    "Day {} example {} part {}: expected {}, found {}",
+                    S::DAY,
+                    i + 1,
+                    part,
+                    expected,
+                    answer
+                );
+            }
+        }
+    }
+}
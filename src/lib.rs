@@ -1 +1,8 @@
+pub mod bench;
+pub mod days;
+pub mod example;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
+pub mod verify;
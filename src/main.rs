@@ -1,3 +1,605 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use aoc2015::bench::{self, BenchOptions, DayBench};
+use aoc2015::days;
+use aoc2015::runner::{self, InputSource, OutputFormat, PartSelection, RunError};
+use aoc2015::scaffold;
+use aoc2015::solution::{DynSolution, Params};
+use aoc2015::utils::ParseError;
+use aoc2015::verify::{self, AnswerKey, Verdict};
+
+const FIRST_DAY: u64 = 1;
+const LAST_DAY: u64 = 25;
+
+const COMMANDS: [&str; 4] = ["bench", "verify", "examples", "scaffold"];
+
+const USAGE: &str = "\
+Usage: aoc2015 [bench | verify | examples] [DAYS]... [--part <1|2>]
+               [--input <PATH> | --input-dir <DIR>...] [--format <FORMAT>] [--threads <N>]
+               [--param <NAME=VALUE>...]
+       aoc2015 scaffold <DAY> --name <NAME> [--year <YEAR>] [--root <DIR>]
+
+Runs the AOC 2015 solutions for the selected days and prints the solutions and execution times.
+
+Commands:
+  bench                    Benchmark the selected days over repeated runs instead of solving
+                           them once
+  verify                   Check the solutions against the expected answers for each input and
+                           report PASS, FAIL or MISSING for each part
+  examples                 Solve the worked examples from the problem statements and check the
+                           expected answers
+  scaffold                 Create the module for a new day from \"template/day00.rs\" and add it
+                           to the solution registry
+
+Arguments:
+  [DAYS]...                Days to run - a single day (\"7\"), an inclusive range (\"3-9\") or
+                           \"all\" (default: all)
+
+Options:
+  -p, --part <1|2>         Only solve the given part of each day (default: both parts)
+  -i, --input <PATH>       Read the input from the given file, or from stdin if PATH is \"-\"
+                           (requires a single day to be selected)
+  -d, --input-dir <DIR>    Read the input for each day from \"<DIR>/dayNN.txt\" - can be given
+                           multiple times to solve several sets of inputs
+  -f, --format <FORMAT>    Output format for the results - \"text\", \"json\" (one JSON object
+                           per line), \"csv\" or \"tsv\" (default: text)
+  -j, --threads <N>        Number of threads used to solve the selected days concurrently - the
+                           results are still printed in day order (default: 1)
+  -P, --param <NAME=VALUE> Override the default value of a solver parameter (e.g. Day 14
+                           \"duration=1000\" or Day 08 \"dialect=rust\") - can be given
+                           multiple times
+  -h, --help               Print this help message
+
+Benchmark options:
+  -w, --warmup <N>         Number of warm-up runs before measuring (default: 3)
+  -n, --runs <N>           Number of measured runs (default: 10)
+  -o, --json <PATH>        Write the benchmark results to the given file as JSON
+  -b, --baseline <PATH>    Compare median execution times against a previous JSON results file
+  -t, --threshold <PCT>    Percentage increase over the baseline median that is reported as a
+                           regression (default: 10)
+
+Verify options:
+  -a, --answers <PATH>     JSON file of expected answers keyed by the MD5 hash of each input
+                           (default: ./answers.json)
+  -r, --record             Add the solutions for any MISSING parts to the answers file
+
+Scaffold options:
+      --name <NAME>        Name of the problem as given in the problem title
+      --year <YEAR>        Year used in the doc comments of the new module (default: 2015)
+      --root <DIR>         Root directory of the project to add the day to (default: .)";
+
+/// Represents the different commands that can be conducted by the runner.
+enum Command {
+    Run {
+        format: OutputFormat,
+        params: Params,
+    },
+    Bench {
+        options: BenchOptions,
+        json: Option<String>,
+        baseline: Option<String>,
+        threshold: f64,
+    },
+    Verify {
+        answers: String,
+        record: bool,
+    },
+    Examples,
+    Scaffold {
+        name: String,
+        year: u64,
+        root: PathBuf,
+    },
+}
+
+/// Command-line options accepted by the runner.
+struct Options {
+    command: Command,
+    days: Vec<u64>,
+    parts: PartSelection,
+    sources: Vec<InputSource>,
+    threads: usize,
+}
+
+impl Options {
+    /// Returns the solution and input source for each day to be solved, grouped by input source.
+    fn jobs(&self) -> Vec<(&'static dyn DynSolution, &InputSource)> {
+        let mut jobs: Vec<(&'static dyn DynSolution, &InputSource)> = vec![];
+        for source in self.sources.iter() {
+            for day in self.days.iter() {
+                let solution = days::get_solution(*day).expect("day already validated");
+                jobs.push((solution, source));
+            }
+        }
+        jobs
+    }
+}
+
+/// Runs the AOC 2015 solutions for the days selected by the command-line arguments.
 fn main() {
-    println!("Hello, world! This is the default binary for project: aoc2015");
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return;
+        }
+        Err(msg) => {
+            eprintln!("[!] {msg}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+    let success = match &options.command {
+        Command::Run { format, params } => run(&options, *format, params),
+        Command::Bench {
+            options: bench_options,
+            json,
+            baseline,
+            threshold,
+        } => run_bench(&options, bench_options, json, baseline, *threshold),
+        Command::Verify { answers, record } => run_verify(&options, answers, *record),
+        Command::Examples => run_examples(&options),
+        Command::Scaffold { name, year, root } => run_scaffold(options.days[0], name, *year, root),
+    };
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Solves the selected days for each input source using the given solver parameter values and
+/// prints the results in the given format. Returns false if any of the inputs could not be
+/// processed.
+fn run(options: &Options, format: OutputFormat, params: &Params) -> bool {
+    let mut success = true;
+    let mut solved = 0;
+    let mut total_duration = Duration::ZERO;
+    let start = Instant::now();
+    format.print_header();
+    let jobs = options.jobs();
+    runner::solve_in_parallel(
+        &jobs,
+        options.parts,
+        params,
+        options.threads,
+        |solution, result| match result {
+            Ok(result) => {
+                result.print_as(format);
+                solved += 1;
+                total_duration += result.total_duration();
+            }
+            Err(err) => {
+                report_run_error(solution, &err);
+                success = false;
+            }
+        },
+    );
+    // The overall execution times are only given alongside the human-readable output
+    if format == OutputFormat::Text && solved > 1 {
+        println!(
+            "[*] {} days solved on {} thread(s) // wall time: {:.2?}, total of day times: {:.2?}",
+            solved,
+            options.threads,
+            start.elapsed(),
+            total_duration
+        );
+    }
+    success
+}
+
+/// Benchmarks the selected days for each input source and prints the results, optionally writing
+/// them to a JSON file and comparing them against a baseline. Returns false if any of the inputs
+/// could not be processed or a regression against the baseline was found.
+fn run_bench(
+    options: &Options,
+    bench_options: &BenchOptions,
+    json: &Option<String>,
+    baseline: &Option<String>,
+    threshold: f64,
+) -> bool {
+    let mut success = true;
+    let mut results: Vec<DayBench> = vec![];
+    for source in options.sources.iter() {
+        for day in options.days.iter() {
+            let solution = days::get_solution(*day).expect("day already validated");
+            match solution.bench(source, options.parts, bench_options) {
+                Ok(result) => {
+                    result.print();
+                    results.push(result);
+                }
+                Err(err) => {
+                    report_input_error(solution, &err);
+                    success = false;
+                }
+            }
+        }
+    }
+    if let Some(filename) = json {
+        if let Err(msg) = bench::write_results(filename, &results, bench_options) {
+            eprintln!("[!] Unable to write benchmark results: {msg}");
+            success = false;
+        }
+    }
+    if let Some(filename) = baseline {
+        match bench::read_baseline(filename) {
+            Ok(baseline) => {
+                let comparisons = bench::compare_to_baseline(&results, &baseline, threshold);
+                bench::print_comparisons(&comparisons, threshold);
+                if comparisons.iter().any(|c| c.is_regression) {
+                    success = false;
+                }
+            }
+            Err(msg) => {
+                eprintln!("[!] Unable to read benchmark baseline: {msg}");
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+/// Solves the selected days for each input source and checks the solutions against the expected
+/// answers, optionally recording the solutions for parts without an expected answer. Returns false
+/// if any of the inputs could not be processed or any solution did not match the expected answer.
+fn run_verify(options: &Options, answers: &str, record: bool) -> bool {
+    let mut key = match AnswerKey::read(answers) {
+        Ok(key) => key,
+        // A missing answers file is created when recording the solutions
+        Err(_) if record && !Path::new(answers).exists() => AnswerKey::default(),
+        Err(msg) => {
+            eprintln!("[!] Unable to read expected answers: {msg}");
+            return false;
+        }
+    };
+    let mut success = true;
+    let (mut passed, mut failed, mut missing, mut recorded) = (0, 0, 0, 0);
+    let jobs = options.jobs();
+    let params = Params::default();
+    runner::solve_in_parallel(
+        &jobs,
+        options.parts,
+        &params,
+        options.threads,
+        |solution, result| {
+            let result = match result {
+                Ok(result) => result,
+                Err(err) => {
+                    report_run_error(solution, &err);
+                    success = false;
+                    return;
+                }
+            };
+            let verification = verify::verify_result(&result, &key);
+            verification.print();
+            for verdict in [&verification.part1, &verification.part2]
+                .into_iter()
+                .flatten()
+            {
+                match verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::Fail { .. } => failed += 1,
+                    Verdict::Missing => missing += 1,
+                }
+            }
+            if record && verification.has_missing() {
+                recorded += key.record(&result);
+            }
+        },
+    );
+    println!("==================================================");
+    println!("[*] {passed} passed, {failed} failed, {missing} missing");
+    if recorded > 0 {
+        match key.write(answers) {
+            Ok(()) => println!("[*] Recorded {recorded} answers in {answers}"),
+            Err(msg) => {
+                eprintln!("[!] Unable to write expected answers: {msg}");
+                success = false;
+            }
+        }
+    }
+    success && failed == 0
+}
+
+/// Solves the worked examples for the selected days and prints the verdict for each solved part.
+/// Returns false if any of the examples could not be parsed or did not produce the expected
+/// answers.
+fn run_examples(options: &Options) -> bool {
+    let (mut passed, mut failed) = (0, 0);
+    for day in options.days.iter() {
+        let solution = days::get_solution(*day).expect("day already validated");
+        for (i, example) in solution.examples().iter().enumerate() {
+            let result = match solution.check_example(example, options.parts) {
+                Ok(result) => result,
+                Err(err) => {
+                    println!(
+                        "[!] FAIL    Day {:02} Example {} - input error: {}",
+                        day,
+                        i + 1,
+                        err
+                    );
+                    failed += 1;
+                    continue;
+                }
+            };
+            for (part, outcome) in [(1, &result.part1), (2, &result.part2)] {
+                let Some((answer, verdict)) = outcome else {
+                    continue;
+                };
+                let marker = match verdict {
+                    Verdict::Pass => "[+]",
+                    _ => "[!]",
+                };
+                print!(
+                    "{} {:<7} Day {:02} Example {} Part {} - {}",
+                    marker,
+                    verdict.label(),
+                    day,
+                    i + 1,
+                    part,
+                    answer
+                );
+                if let Verdict::Fail { expected } = verdict {
+                    print!(" (expected {})", expected);
+                }
+                println!(" // {}", example.summary());
+            }
+            if result.is_pass() {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+        }
+    }
+    println!("==================================================");
+    println!("[*] {passed} examples passed, {failed} failed");
+    failed == 0
+}
+
+/// Creates the module for the given day from the template and registers it. Returns false if the
+/// day already exists or the project files could not be read or written.
+fn run_scaffold(day: u64, name: &str, year: u64, root: &Path) -> bool {
+    match scaffold::scaffold_day(root, day, name, year) {
+        Ok(files) => {
+            for file in files {
+                println!("[+] Wrote {}", file.display());
+            }
+            println!("[*] Fill in the remaining \"###\" placeholders to complete Day {day}");
+            true
+        }
+        Err(msg) => {
+            eprintln!("[!] Unable to scaffold Day {day}: {msg}");
+            false
+        }
+    }
+}
+
+/// Prints the error encountered when processing the input for the given solution to stderr.
+fn report_input_error(solution: &dyn DynSolution, err: &ParseError) {
+    eprintln!(
+        "[!] AOC 2015 Day {} - \"{}\" // input error: {}",
+        solution.day(),
+        solution.name(),
+        err
+    );
+}
+
+/// Prints the error encountered when solving the problem for the given solution to stderr.
+fn report_run_error(solution: &dyn DynSolution, err: &RunError) {
+    match err {
+        RunError::Parse(err) => report_input_error(solution, err),
+        RunError::Panicked { .. } => eprintln!(
+            "[!] AOC 2015 Day {} - \"{}\" // {}",
+            solution.day(),
+            solution.name(),
+            err
+        ),
+    }
+}
+
+/// Parses the command-line arguments into the runner options. Returns None if the help message
+/// was requested.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut args = args.peekable();
+    let command_name = args.next_if(|arg| COMMANDS.contains(&arg.as_str()));
+    let is_bench = command_name.as_deref() == Some("bench");
+    let is_verify = command_name.as_deref() == Some("verify");
+    let is_examples = command_name.as_deref() == Some("examples");
+    let is_scaffold = command_name.as_deref() == Some("scaffold");
+    let mut bench_options = BenchOptions::default();
+    let mut json: Option<String> = None;
+    let mut baseline: Option<String> = None;
+    let mut threshold = bench::DEFAULT_REGRESSION_THRESHOLD;
+    let mut format = OutputFormat::Text;
+    let mut answers = String::from(verify::DEFAULT_ANSWERS_FILE);
+    let mut record = false;
+    let mut param_values: Vec<(String, String)> = vec![];
+    let mut name: Option<String> = None;
+    let mut year = scaffold::DEFAULT_YEAR;
+    let mut root = PathBuf::from(".");
+    let mut threads = 1;
+    let mut days: Vec<u64> = vec![];
+    let mut parts = PartSelection::Both;
+    let mut input: Option<InputSource> = None;
+    let mut input_dirs: Vec<InputSource> = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-p" | "--part" => {
+                parts = match args.next().as_deref() {
+                    Some("1") => PartSelection::Part1,
+                    Some("2") => PartSelection::Part2,
+                    Some(other) => return Err(format!("Invalid part: \"{other}\"")),
+                    None => return Err(String::from("Missing value for --part")),
+                };
+            }
+            "-i" | "--input" => {
+                input = match args.next().as_deref() {
+                    Some("-") => Some(InputSource::Stdin),
+                    Some(path) => Some(InputSource::File(PathBuf::from(path))),
+                    None => return Err(String::from("Missing value for --input")),
+                };
+            }
+            "-d" | "--input-dir" => match args.next() {
+                Some(dir) => input_dirs.push(InputSource::Directory(PathBuf::from(dir))),
+                None => return Err(String::from("Missing value for --input-dir")),
+            },
+            "-f" | "--format" if command_name.is_none() => {
+                format = parse_value(&arg, args.next(), |_| true)?;
+            }
+            "-P" | "--param" if command_name.is_none() => {
+                param_values.push(parse_param(args.next())?);
+            }
+            "-j" | "--threads" if command_name.is_none() || is_verify => {
+                threads = parse_value(&arg, args.next(), |n: &usize| *n > 0)?;
+            }
+            "-w" | "--warmup" if is_bench => {
+                bench_options.warmup_runs = parse_value(&arg, args.next(), |_: &usize| true)?;
+            }
+            "-n" | "--runs" if is_bench => {
+                bench_options.runs = parse_value(&arg, args.next(), |n: &usize| *n > 0)?;
+            }
+            "-o" | "--json" if is_bench => json = Some(parse_value(&arg, args.next(), |_| true)?),
+            "-b" | "--baseline" if is_bench => {
+                baseline = Some(parse_value(&arg, args.next(), |_| true)?);
+            }
+            "-t" | "--threshold" if is_bench => {
+                threshold = parse_value(&arg, args.next(), |pct: &f64| *pct >= 0.0)?;
+            }
+            "-a" | "--answers" if is_verify => answers = parse_value(&arg, args.next(), |_| true)?,
+            "-r" | "--record" if is_verify => record = true,
+            "--name" if is_scaffold => name = Some(parse_value(&arg, args.next(), |_| true)?),
+            "--year" if is_scaffold => year = parse_value(&arg, args.next(), |_| true)?,
+            "--root" if is_scaffold => root = parse_value(&arg, args.next(), |_| true)?,
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: \"{arg}\"")),
+            _ => days.extend(parse_days(&arg)?),
+        }
+    }
+    if is_scaffold {
+        let Some(name) = name else {
+            return Err(String::from("scaffold requires the problem --name"));
+        };
+        if days.len() != 1 {
+            return Err(String::from(
+                "scaffold requires a single day to be selected",
+            ));
+        }
+        return Ok(Some(Options {
+            command: Command::Scaffold { name, year, root },
+            days,
+            parts,
+            sources: vec![],
+            threads,
+        }));
+    }
+    if days.is_empty() {
+        days.extend(FIRST_DAY..=LAST_DAY);
+    }
+    days.sort_unstable();
+    days.dedup();
+    if is_examples && (input.is_some() || !input_dirs.is_empty()) {
+        return Err(String::from("examples use their own inputs"));
+    }
+    let mut params = Params::default();
+    for (name, value) in param_values {
+        let param = days
+            .iter()
+            .flat_map(|day| {
+                let solution = days::get_solution(*day).expect("day already validated");
+                solution.params().iter()
+            })
+            .find(|param| param.name == name);
+        let Some(param) = param else {
+            return Err(format!(
+                "Unknown parameter for the selected days: \"{name}\""
+            ));
+        };
+        let value = param
+            .parse_value(&value)
+            .map_err(|msg| format!("Invalid value for parameter \"{name}\": {msg}"))?;
+        params.set(&name, value);
+    }
+    let sources = match input {
+        Some(_) if !input_dirs.is_empty() => {
+            return Err(String::from("--input cannot be used with --input-dir"));
+        }
+        Some(_) if days.len() != 1 => {
+            return Err(String::from("--input requires a single day to be selected"));
+        }
+        Some(source) => vec![source],
+        None if input_dirs.is_empty() => vec![InputSource::Default],
+        None => input_dirs,
+    };
+    let command = if is_bench {
+        Command::Bench {
+            options: bench_options,
+            json,
+            baseline,
+            threshold,
+        }
+    } else if is_verify {
+        Command::Verify { answers, record }
+    } else if is_examples {
+        Command::Examples
+    } else {
+        Command::Run { format, params }
+    };
+    Ok(Some(Options {
+        command,
+        days,
+        parts,
+        sources,
+        threads,
+    }))
+}
+
+/// Parses the value given for a command-line option, checking that it is valid.
+fn parse_value<T: FromStr>(
+    option: &str,
+    value: Option<String>,
+    is_valid: impl Fn(&T) -> bool,
+) -> Result<T, String> {
+    let Some(value) = value else {
+        return Err(format!("Missing value for {option}"));
+    };
+    match value.parse::<T>() {
+        Ok(parsed) if is_valid(&parsed) => Ok(parsed),
+        _ => Err(format!("Invalid value for {option}: \"{value}\"")),
+    }
+}
+
+/// Parses the name and value given for a solver parameter override ("NAME=VALUE"). The value is
+/// checked once the parameter has been found for the selected days.
+fn parse_param(value: Option<String>) -> Result<(String, String), String> {
+    let Some(value) = value else {
+        return Err(String::from("Missing value for --param"));
+    };
+    match value.split_once('=') {
+        Some((name, param)) if !name.is_empty() => Ok((name.to_string(), param.to_string())),
+        _ => Err(format!(
+            "Invalid value for --param: \"{value}\" (expected NAME=VALUE)"
+        )),
+    }
+}
+
+/// Parses a single day specification ("7", "3-9" or "all") into the days it selects.
+fn parse_days(spec: &str) -> Result<Vec<u64>, String> {
+    if spec == "all" {
+        return Ok((FIRST_DAY..=LAST_DAY).collect());
+    }
+    let parse_day = |s: &str| match s.trim().parse::<u64>() {
+        Ok(day) if (FIRST_DAY..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Invalid day: \"{s}\" (expected {FIRST_DAY}-{LAST_DAY})"
+        )),
+    };
+    if let Some((start, end)) = spec.split_once('-') {
+        let (start, end) = (parse_day(start)?, parse_day(end)?);
+        if start > end {
+            return Err(format!("Invalid day range: \"{spec}\""));
+        }
+        return Ok((start..=end).collect());
+    }
+    Ok(vec![parse_day(spec)?])
 }
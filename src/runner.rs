@@ -0,0 +1,530 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::solution::{Answer, DynSolution, Params, Solution};
+use crate::utils::ParseError;
+use crate::verify;
+
+/// Represents which parts of a problem are solved when a day is run.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PartSelection {
+    Part1,
+    Part2,
+    Both,
+}
+
+impl PartSelection {
+    /// Checks if Part 1 is included in the selection.
+    pub fn includes_part1(&self) -> bool {
+        matches!(self, PartSelection::Part1 | PartSelection::Both)
+    }
+
+    /// Checks if Part 2 is included in the selection.
+    pub fn includes_part2(&self) -> bool {
+        matches!(self, PartSelection::Part2 | PartSelection::Both)
+    }
+}
+
+/// Represents the format used to print the results of solving each day.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    /// Human-readable banner for each day.
+    Text,
+    /// One JSON object per line for each day.
+    JsonLines,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// Tab-separated values with a header row.
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" | "jsonl" => Ok(OutputFormat::JsonLines),
+            "csv" => Ok(OutputFormat::Csv),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!("unknown output format: \"{s}\"")),
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Returns the field separator used by the delimited formats.
+    fn separator(&self) -> Option<char> {
+        match self {
+            OutputFormat::Csv => Some(','),
+            OutputFormat::Tsv => Some('\t'),
+            _ => None,
+        }
+    }
+
+    /// Prints the header row for the output format, if the format has one.
+    pub fn print_header(&self) {
+        if let Some(separator) = self.separator() {
+            println!("{}", DELIMITED_COLUMNS.join(&separator.to_string()));
+        }
+    }
+}
+
+/// Column names used in the header row of the delimited output formats.
+const DELIMITED_COLUMNS: [&str; 11] = [
+    "day",
+    "name",
+    "input",
+    "part1",
+    "part1_type",
+    "part2",
+    "part2_type",
+    "input_ns",
+    "part1_ns",
+    "part2_ns",
+    "total_ns",
+];
+
+/// Represents the location that the input for a problem is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// Default input file for the problem (e.g. "./input/day07.txt").
+    Default,
+    /// Input file at the given path.
+    File(PathBuf),
+    /// Input file named after the problem day (e.g. "day07.txt") within the given directory.
+    Directory(PathBuf),
+    /// Input read from stdin.
+    Stdin,
+}
+
+impl InputSource {
+    /// Returns the location of the input for the given day, using the default input file if no
+    /// other location has been specified.
+    pub fn location(&self, day: u64, default_file: &str) -> String {
+        match self {
+            InputSource::Default => default_file.to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Directory(dir) => {
+                dir.join(format!("day{:02}.txt", day)).display().to_string()
+            }
+            InputSource::Stdin => String::from("<stdin>"),
+        }
+    }
+
+    /// Reads the raw input for the given day.
+    pub fn read(&self, day: u64, default_file: &str) -> Result<String, ParseError> {
+        let location = self.location(day, default_file);
+        let result = match self {
+            InputSource::Stdin => {
+                let mut raw_input = String::new();
+                io::stdin()
+                    .read_to_string(&mut raw_input)
+                    .map(|_| raw_input)
+            }
+            _ => fs::read_to_string(&location),
+        };
+        result.map_err(|err| ParseError::unreadable(&location, &err))
+    }
+}
+
+/// Represents a failure to solve the problem for a single day, either because the input could not
+/// be read or processed, or because the solver panicked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RunError {
+    /// The input could not be read or processed.
+    Parse(ParseError),
+    /// The solver panicked while processing the input (part is None) or solving the given part.
+    Panicked {
+        day: u64,
+        part: Option<u64>,
+        message: String,
+    },
+}
+
+impl From<ParseError> for RunError {
+    fn from(err: ParseError) -> RunError {
+        RunError::Parse(err)
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Parse(err) => write!(f, "{}", err),
+            RunError::Panicked {
+                part: Some(part),
+                message,
+                ..
+            } => write!(
+                f,
+                "solver panicked while solving part {}: {}",
+                part, message
+            ),
+            RunError::Panicked {
+                part: None,
+                message,
+                ..
+            } => write!(f, "solver panicked while processing the input: {}", message),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+/// Holds the solutions and execution times recorded when solving the problem for a single day.
+/// Solutions (and their execution times) are None if the part was not selected to be solved.
+pub struct DayResult {
+    pub day: u64,
+    pub name: &'static str,
+    pub input: String,
+    pub is_default_input: bool,
+    pub input_hash: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub input_duration: Duration,
+    pub part1_duration: Option<Duration>,
+    pub part2_duration: Option<Duration>,
+}
+
+impl DayResult {
+    /// Returns the total execution time across the input processing and solved parts.
+    pub fn total_duration(&self) -> Duration {
+        self.input_duration
+            + self.part1_duration.unwrap_or_default()
+            + self.part2_duration.unwrap_or_default()
+    }
+
+    /// Prints the solutions and execution times to stdout.
+    pub fn print(&self) {
+        println!("==================================================");
+        println!("AOC 2015 Day {} - \"{}\"", self.day, self.name);
+        if !self.is_default_input {
+            println!("Input: {}", self.input);
+        }
+        if let Some(solution) = &self.part1 {
+            println!("[+] Part 1: {}", solution);
+        }
+        if let Some(solution) = &self.part2 {
+            println!("[+] Part 2: {}", solution);
+        }
+        println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+        println!("Execution times:");
+        println!("[+] Input:  {:.2?}", self.input_duration);
+        if let Some(duration) = self.part1_duration {
+            println!("[+] Part 1: {:.2?}", duration);
+        }
+        if let Some(duration) = self.part2_duration {
+            println!("[+] Part 2: {:.2?}", duration);
+        }
+        println!("[*] TOTAL:  {:.2?}", self.total_duration());
+        println!("==================================================");
+    }
+
+    /// Prints the result to stdout in the given output format.
+    pub fn print_as(&self, format: OutputFormat) {
+        match format.separator() {
+            Some(separator) => println!("{}", self.to_record(separator)),
+            None if format == OutputFormat::JsonLines => println!("{}", self.to_json()),
+            None => self.print(),
+        }
+    }
+
+    /// Converts the result into a JSON object, with the answers retaining their native types and
+    /// the execution times given in nanoseconds. Parts that were not solved are null.
+    pub fn to_json(&self) -> Value {
+        let part_json = |answer: &Option<Answer>| match answer {
+            Some(answer) => json!({"answer": answer.to_json(), "type": answer.type_name()}),
+            None => Value::Null,
+        };
+        json!({
+            "day": self.day,
+            "name": self.name,
+            "input": self.input,
+            "input_hash": self.input_hash,
+            "part1": part_json(&self.part1),
+            "part2": part_json(&self.part2),
+            "durations_ns": {
+                "input": self.input_duration.as_nanos() as u64,
+                "part1": self.part1_duration.map(|d| d.as_nanos() as u64),
+                "part2": self.part2_duration.map(|d| d.as_nanos() as u64),
+                "total": self.total_duration().as_nanos() as u64,
+            },
+        })
+    }
+
+    /// Converts the result into a single delimited record, matching the columns of the header
+    /// row. Parts that were not solved are left empty.
+    pub fn to_record(&self, separator: char) -> String {
+        let answer = |answer: &Option<Answer>| match answer {
+            Some(answer) => (answer.to_string(), answer.type_name().to_string()),
+            None => (String::new(), String::new()),
+        };
+        let nanos = |duration: Option<Duration>| match duration {
+            Some(duration) => duration.as_nanos().to_string(),
+            None => String::new(),
+        };
+        let (part1, part1_type) = answer(&self.part1);
+        let (part2, part2_type) = answer(&self.part2);
+        let fields = [
+            self.day.to_string(),
+            self.name.to_string(),
+            self.input.clone(),
+            part1,
+            part1_type,
+            part2,
+            part2_type,
+            nanos(Some(self.input_duration)),
+            nanos(self.part1_duration),
+            nanos(self.part2_duration),
+            nanos(Some(self.total_duration())),
+        ];
+        fields
+            .iter()
+            .map(|field| quote_field(field, separator))
+            .collect::<Vec<String>>()
+            .join(&separator.to_string())
+    }
+}
+
+/// Quotes a delimited field if it contains the separator, a double quote or a line break, with
+/// any double quotes within the field doubled.
+fn quote_field(field: &str, separator: char) -> String {
+    if field.contains([separator, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Processes the input for the given solution and solves the selected parts using the given solver
+/// parameter values, timing each phase. Returns an error if the input could not be read or
+/// processed, or if the solver panicked.
+pub fn solve_day<S: Solution>(
+    source: &InputSource,
+    parts: PartSelection,
+    params: &Params,
+) -> Result<DayResult, RunError> {
+    let location = source.location(S::DAY, S::INPUT_FILE);
+    let start = Instant::now();
+    // Input processing
+    let raw_input = source.read(S::DAY, S::INPUT_FILE)?;
+    let input = catch_panic(S::DAY, None, || S::parse_with(&raw_input, params))?
+        .map_err(|err| err.with_file(&location))?;
+    let input_duration = start.elapsed();
+    // Solve part 1
+    let (part1, part1_duration) = if parts.includes_part1() {
+        let timestamp = Instant::now();
+        let solution: Answer =
+            catch_panic(S::DAY, Some(1), || S::part1_with(&input, params))?.into();
+        (Some(solution), Some(timestamp.elapsed()))
+    } else {
+        (None, None)
+    };
+    // Solve part 2
+    let (part2, part2_duration) = if parts.includes_part2() {
+        let timestamp = Instant::now();
+        let solution: Answer =
+            catch_panic(S::DAY, Some(2), || S::part2_with(&input, params))?.into();
+        (Some(solution), Some(timestamp.elapsed()))
+    } else {
+        (None, None)
+    };
+    Ok(DayResult {
+        day: S::DAY,
+        name: S::NAME,
+        input: location,
+        is_default_input: *source == InputSource::Default,
+        input_hash: verify::input_hash(&raw_input),
+        part1,
+        part2,
+        input_duration,
+        part1_duration,
+        part2_duration,
+    })
+}
+
+/// Runs one phase of solving the problem for the given day and part (None for input processing),
+/// returning an error instead of unwinding if the phase panics.
+fn catch_panic<T>(day: u64, part: Option<u64>, phase: impl FnOnce() -> T) -> Result<T, RunError> {
+    panic::catch_unwind(AssertUnwindSafe(phase)).map_err(|payload| RunError::Panicked {
+        day,
+        part,
+        message: panic_message(&*payload),
+    })
+}
+
+/// Solves each of the given days (paired with the source of their input) on the given number of
+/// threads, with each thread taking the next unsolved day in turn. The results are passed to the
+/// callback along with their solution in the same order as the jobs were given, as soon as all of
+/// the preceding jobs have finished. A day whose solver panics is reported as an error, without
+/// stopping the other days from being solved and reported.
+pub fn solve_in_parallel<F>(
+    jobs: &[(&'static dyn DynSolution, &InputSource)],
+    parts: PartSelection,
+    params: &Params,
+    threads: usize,
+    mut on_result: F,
+) where
+    F: FnMut(&'static dyn DynSolution, Result<DayResult, RunError>),
+{
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            let sender = sender.clone();
+            let next_job = &next_job;
+            scope.spawn(move || loop {
+                let i = next_job.fetch_add(1, Ordering::SeqCst);
+                let Some((solution, source)) = jobs.get(i) else {
+                    return;
+                };
+                let result = solution.solve(source, parts, params);
+                if sender.send((i, result)).is_err() {
+                    return;
+                }
+            });
+        }
+        // Only the worker threads hold senders now, so receiving ends once they have all finished
+        drop(sender);
+        let mut pending: BTreeMap<usize, Result<DayResult, RunError>> = BTreeMap::new();
+        let mut next_result = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&next_result) {
+                on_result(jobs[next_result].0, result);
+                next_result += 1;
+            }
+        }
+    });
+}
+
+/// Returns the message given to the panic with the given payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.to_string()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns a result with only Part 1 solved, for use in the output format tests.
+    fn sample_result() -> DayResult {
+        DayResult {
+            day: 7,
+            name: "Some Assembly Required",
+            input: String::from("./input/day07.txt"),
+            is_default_input: true,
+            input_hash: String::new(),
+            part1: Some(Answer::U16(956)),
+            part2: None,
+            input_duration: Duration::from_nanos(1500),
+            part1_duration: Some(Duration::from_nanos(250)),
+            part2_duration: None,
+        }
+    }
+
+    /// Tests that the JSON output retains the native type of the answers.
+    #[test]
+    fn test_day_result_to_json() {
+        let json = sample_result().to_json();
+        assert_eq!(json!(956), json["part1"]["answer"]);
+        assert_eq!(json!("u16"), json["part1"]["type"]);
+        assert_eq!(Value::Null, json["part2"]);
+        assert_eq!(json!(1750), json["durations_ns"]["total"]);
+    }
+
+    /// Tests that the results of solving days in parallel are returned in the order of the jobs.
+    #[test]
+    fn test_solve_in_parallel_order() {
+        let day09 = crate::days::get_solution(9).unwrap();
+        let day01 = crate::days::get_solution(1).unwrap();
+        let source = InputSource::Default;
+        let jobs = [(day09, &source), (day01, &source), (day09, &source)];
+        let mut days: Vec<u64> = vec![];
+        solve_in_parallel(
+            &jobs,
+            PartSelection::Part1,
+            &Params::default(),
+            3,
+            |_, result| days.push(result.unwrap().day),
+        );
+        assert_eq!(vec![9, 1, 9], days);
+    }
+
+    /// Tests that a day whose solver panics is reported as an error, with the other days still
+    /// reported in order.
+    #[test]
+    fn test_solve_in_parallel_panic() {
+        let day22 = crate::days::get_solution(22).unwrap();
+        let day01 = crate::days::get_solution(1).unwrap();
+        // Boss that no sequence of spells can defeat, which the Day 22 solver panics on
+        let path = std::env::temp_dir().join("aoc2015_test_solve_in_parallel_panic.txt");
+        fs::write(&path, "Hit Points: 1000\nDamage: 50\n").unwrap();
+        let default_source = InputSource::Default;
+        let panic_source = InputSource::File(path.clone());
+        let jobs = [
+            (day01, &default_source),
+            (day22, &panic_source),
+            (day01, &default_source),
+            (day01, &default_source),
+        ];
+        let mut results: Vec<Result<u64, RunError>> = vec![];
+        solve_in_parallel(
+            &jobs,
+            PartSelection::Part1,
+            &Params::default(),
+            2,
+            |_, result| results.push(result.map(|result| result.day)),
+        );
+        fs::remove_file(&path).unwrap();
+        assert_eq!(4, results.len());
+        assert_eq!(Ok(1), results[0]);
+        let err = results[1].clone().unwrap_err();
+        assert!(matches!(
+            err,
+            RunError::Panicked {
+                day: 22,
+                part: Some(1),
+                ..
+            }
+        ));
+        assert!(err
+            .to_string()
+            .starts_with("solver panicked while solving part 1: Player was unable to defeat"));
+        assert_eq!(vec![Ok(1), Ok(1)], results[2..].to_vec());
+    }
+
+    /// Tests the delimited record output, including the quoting of fields containing the separator.
+    #[test]
+    fn test_day_result_to_record() {
+        let mut result = sample_result();
+        assert_eq!(
+            "7\tSome Assembly Required\t./input/day07.txt\t956\tu16\t\t\t1500\t250\t\t1750",
+            result.to_record('\t')
+        );
+        result.name = "Science for Hungry People, \"Really\"";
+        assert!(result
+            .to_record(',')
+            .starts_with("7,\"Science for Hungry People, \"\"Really\"\"\",./input"));
+    }
+}
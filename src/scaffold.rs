@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Location of the day module template, relative to the project root.
+pub const TEMPLATE_FILE: &str = "template/day00.rs";
+/// Location of the directory holding the day modules, relative to the project root.
+pub const DAYS_DIR: &str = "src/days";
+/// Year used in the doc comments of the generated day modules if no other year is given.
+pub const DEFAULT_YEAR: u64 = 2015;
+
+/// Fills in the day module template for the given day, problem name and year. The remaining "###"
+/// placeholders (e.g. the expected answers) are left to be filled in by hand.
+pub fn render_template(template: &str, day: u64, name: &str, year: u64) -> String {
+    template
+        .replace(
+            "pub const PROBLEM_NAME: &str = \"###\";",
+            &format!("pub const PROBLEM_NAME: &str = {:?};", name),
+        )
+        .replace(
+            "pub const PROBLEM_DAY: u64 = 0;",
+            &format!("pub const PROBLEM_DAY: u64 = {};", day),
+        )
+        .replace("AOC 2015", &format!("AOC {}", year))
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("day00", &format!("day{:02}", day))
+        .replace("Day 00", &format!("Day {:02}", day))
+}
+
+/// Adds the module declaration and solution registry entry for the given day to the contents of
+/// the days module, keeping both in day order. Returns an error if the day is already registered
+/// or the registry could not be found.
+pub fn register_day(days_mod: &str, day: u64) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let mod_line = format!("pub mod {};", module);
+    let entry_line = format!("    &{}::Day{:02},", module, day);
+    if days_mod.lines().any(|line| line == mod_line) {
+        return Err(format!("Day {} is already registered", day));
+    }
+    let mut lines: Vec<String> = days_mod.lines().map(|line| line.to_string()).collect();
+    // Insert the module declaration before the first module for a later day
+    let mod_lines = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .map(|(i, line)| (i, line.to_string()))
+        .collect::<Vec<(usize, String)>>();
+    let mod_index = match mod_lines.iter().find(|(_, line)| *line > mod_line) {
+        Some((i, _)) => *i,
+        None => match mod_lines.last() {
+            Some((i, _)) => i + 1,
+            None => return Err(String::from("no day module declarations found")),
+        },
+    };
+    lines.insert(mod_index, mod_line);
+    // Increase the registry length and insert the registry entry in day order
+    let Some(registry_start) = lines
+        .iter()
+        .position(|line| line.starts_with("pub static SOLUTIONS: [&dyn DynSolution; "))
+    else {
+        return Err(String::from("solution registry not found"));
+    };
+    let Some(registry_end) = lines[registry_start..]
+        .iter()
+        .position(|line| line == "];")
+        .map(|i| registry_start + i)
+    else {
+        return Err(String::from("end of solution registry not found"));
+    };
+    let header = &lines[registry_start];
+    let length = header
+        .trim_start_matches("pub static SOLUTIONS: [&dyn DynSolution; ")
+        .trim_end_matches("] = [")
+        .parse::<usize>()
+        .map_err(|_| format!("unexpected solution registry declaration: \"{}\"", header))?;
+    lines[registry_start] = format!(
+        "pub static SOLUTIONS: [&dyn DynSolution; {}] = [",
+        length + 1
+    );
+    let entry_index = (registry_start + 1..registry_end)
+        .find(|i| lines[*i] > entry_line)
+        .unwrap_or(registry_end);
+    lines.insert(entry_index, entry_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the module for the given day from the template within the project at the given root
+/// directory, and registers it in the days module. Refuses to overwrite an existing day. Returned
+/// value is the list of files that were created or updated.
+pub fn scaffold_day(root: &Path, day: u64, name: &str, year: u64) -> Result<Vec<PathBuf>, String> {
+    let template_file = root.join(TEMPLATE_FILE);
+    let day_file = root.join(DAYS_DIR).join(format!("day{:02}.rs", day));
+    let mod_file = root.join(DAYS_DIR).join("mod.rs");
+    if day_file.exists() {
+        return Err(format!("{} already exists", day_file.display()));
+    }
+    let template = fs::read_to_string(&template_file)
+        .map_err(|err| format!("{}: {}", template_file.display(), err))?;
+    let days_mod =
+        fs::read_to_string(&mod_file).map_err(|err| format!("{}: {}", mod_file.display(), err))?;
+    // Update the days module first so nothing is written if the day is already registered
+    let days_mod = register_day(&days_mod, day)?;
+    fs::write(&day_file, render_template(&template, day, name, year))
+        .map_err(|err| format!("{}: {}", day_file.display(), err))?;
+    fs::write(&mod_file, days_mod).map_err(|err| format!("{}: {}", mod_file.display(), err))?;
+    Ok(vec![day_file, mod_file])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the day number, problem name and year are filled in throughout the template.
+    #[test]
+    fn test_render_template() {
+        let template = fs::read_to_string(TEMPLATE_FILE).unwrap();
+        let module = render_template(&template, 7, "Some \"Assembly\" Required", 2016);
+        assert!(
+            module.contains("pub const PROBLEM_NAME: &str = \"Some \\\"Assembly\\\" Required\";")
+        );
+        assert!(module.contains("pub const PROBLEM_INPUT_FILE: &str = \"./input/day07.txt\";"));
+        assert!(module.contains("pub const PROBLEM_DAY: u64 = 7;"));
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("/// Solves AOC 2016 Day 07 Part 1"));
+        assert!(module.contains("fn test_day07_part1_actual()"));
+        assert!(!module.contains("00"));
+    }
+
+    /// Tests that a new day is registered in day order and that existing days are refused.
+    #[test]
+    fn test_register_day() {
+        let days_mod = "\
+use crate::solution::DynSolution;
+
+pub mod day01;
+pub mod day03;
+
+pub static SOLUTIONS: [&dyn DynSolution; 2] = [
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+        let expected = "\
+use crate::solution::DynSolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub static SOLUTIONS: [&dyn DynSolution; 3] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+";
+        assert_eq!(Ok(String::from(expected)), register_day(days_mod, 2));
+        assert!(register_day(days_mod, 3).is_err());
+        let days_mod = register_day(days_mod, 4).unwrap();
+        assert!(days_mod.contains("pub mod day03;\npub mod day04;\n"));
+        assert!(days_mod.contains("    &day03::Day03,\n    &day04::Day04,\n];"));
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::BufRead;
+
+use serde_json::Value;
+
+use crate::bench::{self, BenchOptions, DayBench};
+use crate::example::{self, Example, ExampleResult};
+use crate::runner::{self, DayResult, InputSource, PartSelection, RunError};
+use crate::utils::ParseError;
+
+/// Common interface implemented by the solution for each day of AOC 2015. Provides the problem
+/// metadata along with the input processing and solver functions.
+pub trait Solution {
+    /// Day number of the problem.
+    const DAY: u64;
+    /// Name of the problem as given in the problem title.
+    const NAME: &'static str;
+    /// Default location of the problem input file.
+    const INPUT_FILE: &'static str;
+    /// Solver parameters that can be overridden when solving the problem.
+    const PARAMS: &'static [Param] = &[];
+    /// Worked examples from the problem statement.
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Input format required by the solver functions.
+    type Input;
+    /// Type of the Part 1 solution.
+    type Part1: Into<Answer>;
+    /// Type of the Part 2 solution.
+    type Part2: Into<Answer>;
+
+    /// Parses the raw problem input into the format required by the solver functions.
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the raw problem input using the given solver parameter values. Only needs to be
+    /// implemented by problems whose input format depends on solver parameters.
+    fn parse_with(raw_input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(raw_input)
+    }
+
+    /// Reads the problem input from the given reader and parses it into the format required by
+    /// the solver functions.
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input, ParseError> {
+        let mut raw_input = String::new();
+        reader
+            .read_to_string(&mut raw_input)
+            .map_err(|err| ParseError::unreadable("<reader>", &err))?;
+        Self::parse(&raw_input)
+    }
+
+    /// Solves Part 1 of the problem.
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    /// Solves Part 2 of the problem.
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Solves Part 1 of the problem using the given solver parameter values. Only needs to be
+    /// implemented by problems with solver parameters.
+    fn part1_with(input: &Self::Input, _params: &Params) -> Self::Part1 {
+        Self::part1(input)
+    }
+
+    /// Solves Part 2 of the problem using the given solver parameter values. Only needs to be
+    /// implemented by problems with solver parameters.
+    fn part2_with(input: &Self::Input, _params: &Params) -> Self::Part2 {
+        Self::part2(input)
+    }
+}
+
+/// Function parsing a value given for a solver parameter, returning an error describing why the
+/// value is not valid.
+pub type ParamParser = fn(&str) -> Result<u64, String>;
+
+/// Represents a value used by the solver functions that can be overridden, such as the duration of
+/// the race for Day 14. Values are given as numbers, unless the parameter has its own parser for
+/// the values given (such as the name of the escape dialect for Day 08).
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub description: &'static str,
+    pub parse: Option<ParamParser>,
+}
+
+impl Param {
+    /// Parses the value given for the parameter. Returns an error describing why the value is not
+    /// valid for the parameter.
+    pub fn parse_value(&self, value: &str) -> Result<u64, String> {
+        match self.parse {
+            Some(parse) => parse(value),
+            None => value
+                .parse::<u64>()
+                .map_err(|_| format!("expected a number, found \"{}\"", value)),
+        }
+    }
+}
+
+/// Holds the values given for solver parameters, overriding the parameter defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, u64>,
+}
+
+impl Params {
+    /// Creates a new Params with the given parameter values.
+    pub fn from_pairs(pairs: &[(&str, u64)]) -> Params {
+        let mut params = Params::default();
+        for (name, value) in pairs {
+            params.set(name, *value);
+        }
+        params
+    }
+
+    /// Sets the value of the named parameter.
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Returns the value given for the parameter, or its default value if no value was given.
+    pub fn get(&self, param: &Param) -> u64 {
+        self.values
+            .get(param.name)
+            .copied()
+            .unwrap_or(param.default)
+    }
+
+    /// Returns the names of the parameters that have been given values.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(|name| name.as_str())
+    }
+
+    /// Checks if no parameter values have been given.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Represents the solution to one part of a problem, retaining the native type returned by the
+/// solver function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    U16(u16),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    I64(i64),
+    Isize(isize),
+    Text(String),
+}
+
+impl Answer {
+    /// Returns the name of the native type of the answer.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::U16(_) => "u16",
+            Answer::U64(_) => "u64",
+            Answer::U128(_) => "u128",
+            Answer::Usize(_) => "usize",
+            Answer::I64(_) => "i64",
+            Answer::Isize(_) => "isize",
+            Answer::Text(_) => "String",
+        }
+    }
+
+    /// Converts the answer into a JSON value. Integers are emitted as JSON numbers, except for u128
+    /// values too large to be represented as a u64 which are emitted as strings.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::U16(value) => Value::from(*value),
+            Answer::U64(value) => Value::from(*value),
+            Answer::U128(value) => match u64::try_from(*value) {
+                Ok(value) => Value::from(value),
+                Err(_) => Value::from(value.to_string()),
+            },
+            Answer::Usize(value) => Value::from(*value),
+            Answer::I64(value) => Value::from(*value),
+            Answer::Isize(value) => Value::from(*value),
+            Answer::Text(value) => Value::from(value.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::U16(value) => write!(f, "{}", value),
+            Answer::U64(value) => write!(f, "{}", value),
+            Answer::U128(value) => write!(f, "{}", value),
+            Answer::Usize(value) => write!(f, "{}", value),
+            Answer::I64(value) => write!(f, "{}", value),
+            Answer::Isize(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(
+    u16 => U16,
+    u64 => U64,
+    u128 => U128,
+    usize => Usize,
+    i64 => I64,
+    isize => Isize,
+    String => Text
+);
+
+/// Object-safe counterpart of the Solution trait, allowing solutions with different input and
+/// solution types to be held together in the solution registry.
+pub trait DynSolution: Sync {
+    /// Returns the day number of the problem.
+    fn day(&self) -> u64;
+
+    /// Returns the name of the problem.
+    fn name(&self) -> &'static str;
+
+    /// Returns the default location of the problem input file.
+    fn input_file(&self) -> &'static str;
+
+    /// Returns the solver parameters that can be overridden when solving the problem.
+    fn params(&self) -> &'static [Param];
+
+    /// Returns the worked examples from the problem statement.
+    fn examples(&self) -> &'static [Example];
+
+    /// Processes the input from the given source and solves the selected parts of the problem,
+    /// using the given solver parameter values.
+    fn solve(
+        &self,
+        source: &InputSource,
+        parts: PartSelection,
+        params: &Params,
+    ) -> Result<DayResult, RunError>;
+
+    /// Solves the given worked example and checks the answers against the expected answers.
+    fn check_example(
+        &self,
+        example: &Example,
+        parts: PartSelection,
+    ) -> Result<ExampleResult, ParseError>;
+
+    /// Benchmarks the input processing and selected parts of the problem.
+    fn bench(
+        &self,
+        source: &InputSource,
+        parts: PartSelection,
+        options: &BenchOptions,
+    ) -> Result<DayBench, ParseError>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+{
+    fn day(&self) -> u64 {
+        S::DAY
+    }
+
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
+    fn input_file(&self) -> &'static str {
+        S::INPUT_FILE
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn solve(
+        &self,
+        source: &InputSource,
+        parts: PartSelection,
+        params: &Params,
+    ) -> Result<DayResult, RunError> {
+        runner::solve_day::<S>(source, parts, params)
+    }
+
+    fn check_example(
+        &self,
+        example: &Example,
+        parts: PartSelection,
+    ) -> Result<ExampleResult, ParseError> {
+        example::check_example::<S>(example, parts)
+    }
+
+    fn bench(
+        &self,
+        source: &InputSource,
+        parts: PartSelection,
+        options: &BenchOptions,
+    ) -> Result<DayBench, ParseError> {
+        bench::bench_day::<S>(source, parts, options)
+    }
+}
@@ -0,0 +1,949 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, Not};
+use std::str::FromStr;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+
+use super::CircuitProgram;
+use crate::utils::ParseError;
+
+lazy_static! {
+    static ref REGEX_VALUE: Regex = Regex::new(r"^([a-z]+|\d+) -> ([a-z]+)$").unwrap();
+    static ref REGEX_UNARY: Regex = Regex::new(r"^NOT ([a-z]+|\d+) -> ([a-z]+)$").unwrap();
+    static ref REGEX_BINARY: Regex =
+        Regex::new(r"^([a-z]+|\d+) (AND|LSHIFT|RSHIFT|OR) ([a-z]+|\d+) -> ([a-z]+)$").unwrap();
+}
+
+/// Represents the different operations that can provide the value feeding into a wire. Each term
+/// is either a specific value or the name of another wire.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Value { left: String },
+    And { left: String, right: String },
+    LShift { left: String, right: String },
+    RShift { left: String, right: String },
+    Not { left: String },
+    Or { left: String, right: String },
+}
+
+impl Operation {
+    /// Returns the terms (specific values or wire names) used as inputs to the operation.
+    pub fn terms(&self) -> Vec<&str> {
+        match self {
+            Operation::Value { left } | Operation::Not { left } => vec![left],
+            Operation::And { left, right }
+            | Operation::LShift { left, right }
+            | Operation::RShift { left, right }
+            | Operation::Or { left, right } => vec![left, right],
+        }
+    }
+
+    /// Returns the name of the gate performing the operation.
+    pub fn gate_name(&self) -> &'static str {
+        match self {
+            Operation::Value { .. } => "VALUE",
+            Operation::And { .. } => "AND",
+            Operation::LShift { .. } => "LSHIFT",
+            Operation::RShift { .. } => "RSHIFT",
+            Operation::Not { .. } => "NOT",
+            Operation::Or { .. } => "OR",
+        }
+    }
+
+    /// Returns the names of the wires used as inputs to the operation.
+    pub fn input_wires(&self) -> Vec<&str> {
+        self.terms()
+            .into_iter()
+            .filter(|term| is_wire(term))
+            .collect()
+    }
+}
+
+impl fmt::Display for Operation {
+    /// Formats the operation as written on the left-hand side of a netlist connection.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Value { left } => write!(f, "{}", left),
+            Operation::Not { left } => write!(f, "NOT {}", left),
+            Operation::And { left, right }
+            | Operation::LShift { left, right }
+            | Operation::RShift { left, right }
+            | Operation::Or { left, right } => {
+                write!(f, "{} {} {}", left, self.gate_name(), right)
+            }
+        }
+    }
+}
+
+/// Represents the unsigned integer types that can be used as the signals carried by the wires of a
+/// circuit. Shifting by the width of the signal or more shifts out all of the bits, giving zero.
+pub trait Word:
+    Copy
+    + Default
+    + Eq
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + Into<u64>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+{
+    /// Number of bits in the signal.
+    const BITS: u32;
+
+    /// Shifts the value left by the given number of bits, giving zero if all bits are shifted out.
+    fn shift_left(self, amount: Self) -> Self;
+
+    /// Shifts the value right by the given number of bits, giving zero if all bits are shifted
+    /// out.
+    fn shift_right(self, amount: Self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(
+            impl Word for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                fn shift_left(self, amount: Self) -> Self {
+                    u32::try_from(amount)
+                        .ok()
+                        .and_then(|amount| self.checked_shl(amount))
+                        .unwrap_or(0)
+                }
+
+                fn shift_right(self, amount: Self) -> Self {
+                    u32::try_from(amount)
+                        .ok()
+                        .and_then(|amount| self.checked_shr(amount))
+                        .unwrap_or(0)
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, u64);
+
+/// Represents the errors that can occur when determining the value provided to a wire.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The wire is not provided with a value by any operation. The wire that requires its value is
+    /// None if the wire was the target wire.
+    MissingWire {
+        wire: String,
+        required_by: Option<String>,
+    },
+    /// The value of a wire depends on itself through the given path of wires, which starts and ends
+    /// with the same wire.
+    Cycle { path: Vec<String> },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::MissingWire {
+                wire,
+                required_by: Some(required_by),
+            } => write!(
+                f,
+                "wire \"{}\" (required by \"{}\") has no input",
+                wire, required_by
+            ),
+            CircuitError::MissingWire { wire, .. } => write!(f, "wire \"{}\" has no input", wire),
+            CircuitError::Cycle { path } => {
+                write!(f, "feedback loop between wires {}", path.join(" -> "))
+            }
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+/// Represents a circuit of wires and bitwise logic gates as described in the AOC 2015 Day 07
+/// problem (<https://adventofcode.com/2015/day/7>). The wires carry signals of the given word type,
+/// being 16-bit signals unless another width is chosen when the netlist is parsed (e.g.
+/// `Circuit::<u32>::new(netlist)`).
+///
+/// Wire values are evaluated on demand and remembered. When the operation providing the value of a
+/// wire is changed, only the remembered values of that wire and the wires downstream of it are
+/// discarded.
+#[derive(Clone, Debug, Default)]
+pub struct Circuit<W: Word = u16> {
+    wires: Vec<String>, // in the order the wires were first provided with an operation
+    operations: HashMap<String, Operation>,
+    forced: HashMap<String, Option<Operation>>, // operations replaced by forced values
+    fanout: HashMap<String, HashSet<String>>,   // wires fed by each wire
+    values: HashMap<String, W>,
+}
+
+impl<W: Word> Circuit<W> {
+    /// Creates a new Circuit from the given netlist, with one "<operation> -> <wire>" connection
+    /// per line. A later connection to the same wire replaces the earlier one.
+    pub fn new(netlist: &str) -> Result<Circuit<W>, ParseError> {
+        let mut circuit = Circuit::default();
+        for (i, line) in netlist.lines().enumerate() {
+            let column = line.len() - line.trim_start().len() + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // Extract the wire and operation from the current line
+            let (wire, op) = if let Ok(Some(caps)) = REGEX_VALUE.captures(line) {
+                let left = caps[1].to_string();
+                (caps[2].to_string(), Operation::Value { left })
+            } else if let Ok(Some(caps)) = REGEX_UNARY.captures(line) {
+                let left = caps[1].to_string();
+                (caps[2].to_string(), Operation::Not { left })
+            } else if let Ok(Some(caps)) = REGEX_BINARY.captures(line) {
+                let left = caps[1].to_string();
+                let op_type = caps.get(2).unwrap();
+                let right = caps[3].to_string();
+                let op = match op_type.as_str() {
+                    "AND" => Operation::And { left, right },
+                    "OR" => Operation::Or { left, right },
+                    "LSHIFT" => Operation::LShift { left, right },
+                    "RSHIFT" => Operation::RShift { left, right },
+                    _ => {
+                        let column = column + op_type.start();
+                        let expected = "AND, OR, LSHIFT or RSHIFT";
+                        return Err(ParseError::new(i + 1, column, expected, op_type.as_str()));
+                    }
+                };
+                (caps[4].to_string(), op)
+            } else {
+                let expected =
+                    "<term> -> <wire>, NOT <term> -> <wire> or <term> <op> <term> -> <wire>";
+                return Err(ParseError::new(i + 1, column, expected, line));
+            };
+            // Specific values must fit within a wire signal
+            if let Some(term) = op.terms().into_iter().find(|t| is_out_of_range::<W>(t)) {
+                let column = column + line.find(term).unwrap();
+                let expected = format!("{}-bit value", W::BITS);
+                return Err(ParseError::new(i + 1, column, &expected, term));
+            }
+            circuit.set_operation(&wire, op);
+        }
+        Ok(circuit)
+    }
+
+    /// Returns the names of the wires provided with a value, in the order they were first
+    /// connected.
+    pub fn wires(&self) -> impl Iterator<Item = &str> {
+        self.wires.iter().map(|wire| wire.as_str())
+    }
+
+    /// Returns the operation currently providing the value of the given wire.
+    pub fn operation(&self, wire: &str) -> Option<&Operation> {
+        self.operations.get(wire)
+    }
+
+    /// Checks if the value of the wire has been evaluated and not since been invalidated.
+    pub fn is_evaluated(&self, wire: &str) -> bool {
+        self.values.contains_key(wire)
+    }
+
+    /// Checks if the wire is currently forced to a specific value.
+    pub fn is_forced(&self, wire: &str) -> bool {
+        self.forced.contains_key(wire)
+    }
+
+    /// Overrides the operation providing the value of the given wire. Only the wire and the wires
+    /// downstream of it need to be re-evaluated afterwards.
+    pub fn set_operation(&mut self, wire: &str, op: Operation) {
+        // Disconnect the wire from the inputs of the operation it replaces
+        match self.operations.get(wire) {
+            Some(old_op) => {
+                for input in old_op.input_wires() {
+                    if let Some(fed) = self.fanout.get_mut(input) {
+                        fed.remove(wire);
+                    }
+                }
+            }
+            None => self.wires.push(wire.to_string()),
+        }
+        for input in op.input_wires() {
+            self.fanout
+                .entry(input.to_string())
+                .or_default()
+                .insert(wire.to_string());
+        }
+        self.operations.insert(wire.to_string(), op);
+        self.invalidate(wire);
+    }
+
+    /// Forces the wire to the given value, regardless of the operation that would otherwise provide
+    /// its value. The original operation is restored by releasing the wire.
+    pub fn force(&mut self, wire: &str, value: W) {
+        if !self.forced.contains_key(wire) {
+            let original = self.operations.get(wire).cloned();
+            self.forced.insert(wire.to_string(), original);
+        }
+        let left = value.to_string();
+        self.set_operation(wire, Operation::Value { left });
+    }
+
+    /// Restores the operation that provided the value of a forced wire. Returns false if the wire
+    /// was not forced.
+    pub fn release(&mut self, wire: &str) -> bool {
+        let Some(original) = self.forced.remove(wire) else {
+            return false;
+        };
+        match original {
+            Some(op) => self.set_operation(wire, op),
+            None => {
+                // The wire was not connected before it was forced
+                let op = self.operations.remove(wire).unwrap();
+                for input in op.input_wires() {
+                    if let Some(fed) = self.fanout.get_mut(input) {
+                        fed.remove(wire);
+                    }
+                }
+                self.wires.retain(|w| w != wire);
+                self.invalidate(wire);
+            }
+        }
+        true
+    }
+
+    /// Determines the value provided to the given wire. Returns an error if the wire (or a wire it
+    /// depends on) is not provided with a value, or its value depends on itself.
+    pub fn value(&mut self, target: &str) -> Result<W, CircuitError> {
+        if let Some(&value) = self.values.get(target) {
+            return Ok(value);
+        }
+        let wire_values = &mut self.values;
+        let order = topological_order(&self.operations, &[target], |wire| {
+            wire_values.contains_key(wire)
+        })?;
+        for wire in order {
+            let wire_value = evaluate_operation(&self.operations[wire], wire_values);
+            wire_values.insert(wire.to_string(), wire_value);
+        }
+        Ok(wire_values[target])
+    }
+
+    /// Determines the values provided to each of the given wires, in the same order.
+    pub fn values(&mut self, wires: &[&str]) -> Result<Vec<W>, CircuitError> {
+        wires.iter().map(|wire| self.value(wire)).collect()
+    }
+
+    /// Determines the values provided to all of the connected wires, keyed by wire name.
+    pub fn all_values(&mut self) -> Result<BTreeMap<String, W>, CircuitError> {
+        let mut all_values: BTreeMap<String, W> = BTreeMap::new();
+        for i in 0..self.wires.len() {
+            let wire = self.wires[i].to_string();
+            let value = self.value(&wire)?;
+            all_values.insert(wire, value);
+        }
+        Ok(all_values)
+    }
+
+    /// Returns the wires that need to be evaluated to determine the values of the target wires,
+    /// ordered so that each wire comes after the wires feeding into it. The given input wires are
+    /// treated as having known values, so they (and the wires feeding only into them) are left out.
+    pub fn topological_order(
+        &self,
+        targets: &[&str],
+        inputs: &[&str],
+    ) -> Result<Vec<&str>, CircuitError> {
+        topological_order(&self.operations, targets, |wire| inputs.contains(&wire))
+    }
+
+    /// Renders the circuit as a Graphviz DOT graph. Each gate is a node labelled with its operation
+    /// and each wire is an edge labelled with its name, leading from the gate providing its value
+    /// to the gates using it. Connections of specific values are shown as constant nodes, and
+    /// wires that are not connected or not used are shown as point nodes. If values are to be
+    /// shown, evaluated wires are also labelled with their current value.
+    pub fn to_dot(&self, with_values: bool) -> String {
+        let wire_label = |wire: &str| match self.values.get(wire) {
+            Some(value) if with_values => format!("{} = {}", wire, value),
+            _ => wire.to_string(),
+        };
+        let source = |wire: &str| {
+            if self.operations.contains_key(wire) {
+                format!("gate:{}", wire)
+            } else {
+                format!("input:{}", wire)
+            }
+        };
+        let mut nodes: Vec<String> = vec![];
+        let mut edges: Vec<String> = vec![];
+        for wire in self.wires.iter() {
+            let op = &self.operations[wire];
+            let node = format!("gate:{}", wire);
+            match op {
+                Operation::Value { left } if !is_wire(left) => {
+                    nodes.push(format!("{:?} [label={:?}, shape=plaintext];", node, left));
+                    continue;
+                }
+                Operation::Value { .. } => nodes.push(format!("{:?} [label=\"BUF\"];", node)),
+                _ => nodes.push(format!("{:?} [label={:?}];", node, op.gate_name())),
+            }
+            for (i, term) in op.terms().into_iter().enumerate() {
+                if is_wire(term) {
+                    let label = wire_label(term);
+                    edges.push(format!(
+                        "{:?} -> {:?} [label={:?}];",
+                        source(term),
+                        node,
+                        label
+                    ));
+                } else {
+                    let constant = format!("const:{}:{}", wire, i);
+                    nodes.push(format!(
+                        "{:?} [label={:?}, shape=plaintext];",
+                        constant, term
+                    ));
+                    edges.push(format!("{:?} -> {:?};", constant, node));
+                }
+            }
+        }
+        for wire in self.undriven_wires() {
+            nodes.push(format!("{:?} [shape=point];", source(wire)));
+        }
+        for wire in self.unused_wires() {
+            let sink = format!("output:{}", wire);
+            nodes.push(format!("{:?} [shape=point];", sink));
+            let label = wire_label(wire);
+            edges.push(format!(
+                "{:?} -> {:?} [label={:?}];",
+                source(wire),
+                sink,
+                label
+            ));
+        }
+        let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n    node [shape=box];\n");
+        for line in nodes.iter().chain(edges.iter()) {
+            dot.push_str(&format!("    {}\n", line));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Converts the circuit into a JSON graph, listing each gate along with its operation and
+    /// inputs (wire names or specific values), and each wire along with the gates it feeds. If
+    /// values are to be shown, each wire also has its current value (null if not evaluated).
+    pub fn to_json(&self, with_values: bool) -> Value {
+        let gates: Vec<Value> = self
+            .wires
+            .iter()
+            .map(|wire| {
+                let op = &self.operations[wire];
+                let inputs: Vec<Value> = op
+                    .terms()
+                    .into_iter()
+                    .map(|term| match term.parse::<W>() {
+                        Ok(value) => json!(Into::<u64>::into(value)),
+                        Err(_) => json!(term),
+                    })
+                    .collect();
+                json!({"output": wire, "op": op.gate_name(), "inputs": inputs})
+            })
+            .collect();
+        let undriven = self.undriven_wires();
+        let wires: Vec<Value> = self
+            .wires
+            .iter()
+            .map(|wire| wire.as_str())
+            .chain(undriven.iter().copied())
+            .map(|wire| {
+                let mut entry = json!({
+                    "name": wire,
+                    "driven": self.operations.contains_key(wire),
+                    "consumers": self.consumers(wire),
+                });
+                if with_values {
+                    entry["value"] =
+                        json!(self.values.get(wire).map(|&value| Into::<u64>::into(value)));
+                }
+                entry
+            })
+            .collect();
+        json!({"gates": gates, "wires": wires})
+    }
+
+    /// Returns the wires whose operations use the given wire as an input, sorted by name.
+    fn consumers(&self, wire: &str) -> Vec<&str> {
+        let mut consumers: Vec<&str> = match self.fanout.get(wire) {
+            Some(fed) => fed.iter().map(|w| w.as_str()).collect(),
+            None => vec![],
+        };
+        consumers.sort();
+        consumers
+    }
+
+    /// Returns the wires used as inputs that are not provided with a value, in order of first use.
+    fn undriven_wires(&self) -> Vec<&str> {
+        let mut undriven: Vec<&str> = vec![];
+        let mut seen: HashSet<&str> = HashSet::new();
+        for wire in self.wires.iter() {
+            for input in self.operations[wire].input_wires() {
+                if !self.operations.contains_key(input) && seen.insert(input) {
+                    undriven.push(input);
+                }
+            }
+        }
+        undriven
+    }
+
+    /// Returns the connected wires that are not used as inputs by any operation.
+    fn unused_wires(&self) -> Vec<&str> {
+        self.wires
+            .iter()
+            .filter(|wire| self.fanout.get(*wire).is_none_or(|fed| fed.is_empty()))
+            .map(|wire| wire.as_str())
+            .collect()
+    }
+
+    /// Compiles the circuit into a flat program determining the values of the output wires from
+    /// the values of the input wires.
+    pub fn compile(
+        &self,
+        inputs: &[&str],
+        outputs: &[&str],
+    ) -> Result<CircuitProgram<W>, CircuitError> {
+        CircuitProgram::compile(self, inputs, outputs)
+    }
+
+    /// Converts the circuit back into a netlist, with one "<operation> -> <wire>" connection per
+    /// line in the order the wires were first connected.
+    pub fn to_netlist(&self) -> String {
+        self.wires
+            .iter()
+            .map(|wire| format!("{} -> {}\n", self.operations[wire], wire))
+            .collect()
+    }
+
+    /// Simplifies the circuit down to the gates needed to determine the values of the output
+    /// wires. Gates with only specific values as inputs are folded into values, gates that leave
+    /// their input unchanged (e.g. "x LSHIFT 0" or "x OR 0") are replaced by their input, and gates
+    /// that do not feed into the output wires are removed. Wires without an input are kept as
+    /// inputs to the simplified circuit. Returns an error if the value of an output wire depends
+    /// on itself.
+    pub fn simplify(&self, outputs: &[&str]) -> Result<Circuit<W>, CircuitError> {
+        let operations = &self.operations;
+        let order = topological_order(operations, outputs, |wire| !operations.contains_key(wire))?;
+        let mut simplified: HashMap<&str, Simplified<W>> = HashMap::new();
+        for wire in order {
+            let result = fold_operation(&operations[wire], |term| match term.parse::<W>() {
+                Ok(value) => Term::Value(value),
+                Err(_) => match simplified.get(term) {
+                    Some(Simplified::Value(value)) => Term::Value(*value),
+                    Some(Simplified::Alias(source)) => Term::Wire(source.to_string()),
+                    _ => Term::Wire(term.to_string()),
+                },
+            });
+            simplified.insert(wire, result);
+        }
+        // Find the gates still feeding into the output wires
+        let mut live: HashSet<&str> = HashSet::new();
+        let mut pending: Vec<&str> = outputs.to_vec();
+        while let Some(wire) = pending.pop() {
+            let inputs = match simplified.get(wire) {
+                Some(Simplified::Gate(op)) => op.input_wires(),
+                Some(Simplified::Alias(source)) => vec![source.as_str()],
+                _ => vec![],
+            };
+            for input in inputs {
+                if live.insert(input) {
+                    pending.push(input);
+                }
+            }
+        }
+        let mut circuit = Circuit::default();
+        for wire in self.wires.iter() {
+            let is_output = outputs.contains(&wire.as_str());
+            let op = match simplified.get(wire.as_str()) {
+                Some(Simplified::Gate(op)) if is_output || live.contains(wire.as_str()) => {
+                    op.clone()
+                }
+                Some(Simplified::Value(value)) if is_output => Operation::Value {
+                    left: value.to_string(),
+                },
+                Some(Simplified::Alias(source)) if is_output => Operation::Value {
+                    left: source.to_string(),
+                },
+                _ => continue,
+            };
+            circuit.set_operation(wire, op);
+        }
+        Ok(circuit)
+    }
+
+    /// Discards the remembered values of the given wire and all wires downstream of it. A wire is
+    /// only ever evaluated after the wires feeding it, so the search stops at unevaluated wires.
+    fn invalidate(&mut self, wire: &str) {
+        let mut stale: Vec<String> = vec![wire.to_string()];
+        while let Some(wire) = stale.pop() {
+            if self.values.remove(&wire).is_none() {
+                continue;
+            }
+            if let Some(fed) = self.fanout.get(&wire) {
+                stale.extend(fed.iter().cloned());
+            }
+        }
+    }
+}
+
+/// Orders the wires that need to be evaluated to determine the values of the target wires, so that
+/// each wire comes after the wires feeding into it. Wires that are already resolved are left out.
+/// Returns an error if a wire is not provided with a value, or its value depends on itself.
+fn topological_order<'a>(
+    operations: &'a HashMap<String, Operation>,
+    targets: &[&str],
+    is_resolved: impl Fn(&str) -> bool,
+) -> Result<Vec<&'a str>, CircuitError> {
+    let mut order: Vec<&str> = vec![];
+    let mut done: HashSet<&str> = HashSet::new();
+    for &target in targets {
+        if is_resolved(target) || done.contains(target) {
+            continue;
+        }
+        // Wires currently being ordered, forming the path of dependencies from the target wire
+        let mut path: Vec<&str> = vec![target];
+        let mut on_path: HashSet<&str> = HashSet::from([target]);
+        while let Some(&wire) = path.last() {
+            let Some((wire, op)) = operations.get_key_value(wire) else {
+                let required_by = path.len().checked_sub(2).map(|i| path[i].to_string());
+                return Err(CircuitError::MissingWire {
+                    wire: wire.to_string(),
+                    required_by,
+                });
+            };
+            // Order the input wires before the wire they feed into
+            let pending = op
+                .input_wires()
+                .into_iter()
+                .find(|input| !is_resolved(input) && !done.contains(input));
+            match pending {
+                Some(input) if on_path.contains(input) => {
+                    let start = path.iter().position(|w| *w == input).unwrap();
+                    let mut cycle: Vec<String> =
+                        path[start..].iter().map(|w| w.to_string()).collect();
+                    cycle.push(input.to_string());
+                    return Err(CircuitError::Cycle { path: cycle });
+                }
+                Some(input) => {
+                    path.push(input);
+                    on_path.insert(input);
+                }
+                None => {
+                    order.push(wire);
+                    done.insert(wire);
+                    path.pop();
+                    on_path.remove(wire.as_str());
+                }
+            }
+        }
+    }
+    Ok(order)
+}
+
+/// Represents an input to a gate being simplified, being either a specific value or a wire.
+enum Term<W: Word> {
+    Value(W),
+    Wire(String),
+}
+
+impl<W: Word> fmt::Display for Term<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Value(value) => write!(f, "{}", value),
+            Term::Wire(wire) => write!(f, "{}", wire),
+        }
+    }
+}
+
+/// Represents the simplified form of the value provided to a wire.
+enum Simplified<W: Word> {
+    Value(W),        // specific value
+    Alias(String),   // unchanged value of another wire
+    Gate(Operation), // operation that could not be simplified
+}
+
+/// Simplifies the given operation, with its terms replaced by their simplified forms.
+fn fold_operation<W: Word>(op: &Operation, term: impl Fn(&str) -> Term<W>) -> Simplified<W> {
+    let zero = W::default();
+    let ones = !W::default();
+    let gate = |left: &Term<W>, right: &Term<W>, make: fn(String, String) -> Operation| {
+        Simplified::Gate(make(left.to_string(), right.to_string()))
+    };
+    match op {
+        Operation::Value { left } => match term(left) {
+            Term::Value(value) => Simplified::Value(value),
+            Term::Wire(wire) => Simplified::Alias(wire),
+        },
+        Operation::Not { left } => match term(left) {
+            Term::Value(value) => Simplified::Value(!value),
+            Term::Wire(wire) => Simplified::Gate(Operation::Not { left: wire }),
+        },
+        Operation::And { left, right } => match (term(left), term(right)) {
+            (Term::Value(a), Term::Value(b)) => Simplified::Value(a & b),
+            (Term::Value(v), _) | (_, Term::Value(v)) if v == zero => Simplified::Value(zero),
+            (Term::Value(v), Term::Wire(w)) | (Term::Wire(w), Term::Value(v)) if v == ones => {
+                Simplified::Alias(w)
+            }
+            (Term::Wire(a), Term::Wire(b)) if a == b => Simplified::Alias(a),
+            (a, b) => gate(&a, &b, |left, right| Operation::And { left, right }),
+        },
+        Operation::Or { left, right } => match (term(left), term(right)) {
+            (Term::Value(a), Term::Value(b)) => Simplified::Value(a | b),
+            (Term::Value(v), _) | (_, Term::Value(v)) if v == ones => Simplified::Value(ones),
+            (Term::Value(v), Term::Wire(w)) | (Term::Wire(w), Term::Value(v)) if v == zero => {
+                Simplified::Alias(w)
+            }
+            (Term::Wire(a), Term::Wire(b)) if a == b => Simplified::Alias(a),
+            (a, b) => gate(&a, &b, |left, right| Operation::Or { left, right }),
+        },
+        Operation::LShift { left, right } => match (term(left), term(right)) {
+            (Term::Value(a), Term::Value(b)) => Simplified::Value(a.shift_left(b)),
+            (Term::Value(v), _) if v == zero => Simplified::Value(zero),
+            (Term::Wire(w), Term::Value(v)) if v == zero => Simplified::Alias(w),
+            (_, Term::Value(v)) if ones.shift_left(v) == zero => Simplified::Value(zero),
+            (a, b) => gate(&a, &b, |left, right| Operation::LShift { left, right }),
+        },
+        Operation::RShift { left, right } => match (term(left), term(right)) {
+            (Term::Value(a), Term::Value(b)) => Simplified::Value(a.shift_right(b)),
+            (Term::Value(v), _) if v == zero => Simplified::Value(zero),
+            (Term::Wire(w), Term::Value(v)) if v == zero => Simplified::Alias(w),
+            (_, Term::Value(v)) if ones.shift_right(v) == zero => Simplified::Value(zero),
+            (a, b) => gate(&a, &b, |left, right| Operation::RShift { left, right }),
+        },
+    }
+}
+
+/// Evaluates the value of the given operation, with the values of its input wires already known.
+fn evaluate_operation<W: Word>(op: &Operation, wire_values: &HashMap<String, W>) -> W {
+    let value = |term: &String| get_term_value(term, wire_values);
+    match op {
+        Operation::Value { left } => value(left),
+        Operation::And { left, right } => value(left) & value(right),
+        Operation::LShift { left, right } => value(left).shift_left(value(right)),
+        Operation::RShift { left, right } => value(left).shift_right(value(right)),
+        Operation::Not { left } => !value(left),
+        Operation::Or { left, right } => value(left) | value(right),
+    }
+}
+
+/// Checks if the given term is the name of a wire, rather than a specific value.
+fn is_wire(term: &str) -> bool {
+    !term.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Checks if the given term is a specific value that does not fit within a wire signal.
+fn is_out_of_range<W: Word>(term: &str) -> bool {
+    !is_wire(term) && term.parse::<W>().is_err()
+}
+
+/// Gets the value of the given term, if it is a specific value or the name of an evaluated wire.
+fn get_term_value<W: Word>(term: &String, wire_values: &HashMap<String, W>) -> W {
+    if let Ok(value) = term.parse::<W>() {
+        value
+    } else {
+        wire_values[term]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const NETLIST: &str = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\n\
+                           y RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i\n";
+
+    /// Tests that all wire values are determined for the sample circuit from the problem statement.
+    #[test]
+    fn test_circuit_all_values() {
+        let mut circuit: Circuit = Circuit::new(NETLIST).unwrap();
+        let expected = [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ]
+        .map(|(wire, value)| (String::from(wire), value));
+        assert_eq!(Ok(BTreeMap::from(expected)), circuit.all_values());
+        assert_eq!(Ok(vec![65412, 72]), circuit.values(&["h", "d"]));
+    }
+
+    /// Tests that forcing a wire only invalidates the wires downstream of it, and that releasing
+    /// the wire restores its original operation.
+    #[test]
+    fn test_circuit_force_and_release() {
+        let mut circuit: Circuit = Circuit::new(NETLIST).unwrap();
+        circuit.all_values().unwrap();
+        circuit.force("x", 0);
+        assert!(circuit.is_forced("x"));
+        for wire in ["x", "d", "e", "f", "h"] {
+            assert!(!circuit.is_evaluated(wire));
+        }
+        for wire in ["y", "g", "i"] {
+            assert!(circuit.is_evaluated(wire));
+        }
+        assert_eq!(Ok(vec![0, 456, 65535]), circuit.values(&["d", "e", "h"]));
+        assert!(circuit.release("x"));
+        assert!(!circuit.release("x"));
+        assert_eq!(Ok(72), circuit.value("d"));
+    }
+
+    /// Tests that the circuit is rendered as a DOT graph with gates as nodes and wires as edges
+    /// labelled with their evaluated values.
+    #[test]
+    fn test_circuit_to_dot() {
+        let mut circuit: Circuit =
+            Circuit::new("3 -> x\nx LSHIFT 2 -> f\nNOT f -> h\ny OR f -> z\n").unwrap();
+        circuit.value("h").unwrap();
+        let expected = "\
+digraph circuit {
+    rankdir=LR;
+    node [shape=box];
+    \"gate:x\" [label=\"3\", shape=plaintext];
+    \"gate:f\" [label=\"LSHIFT\"];
+    \"const:f:1\" [label=\"2\", shape=plaintext];
+    \"gate:h\" [label=\"NOT\"];
+    \"gate:z\" [label=\"OR\"];
+    \"input:y\" [shape=point];
+    \"output:h\" [shape=point];
+    \"output:z\" [shape=point];
+    \"gate:x\" -> \"gate:f\" [label=\"x = 3\"];
+    \"const:f:1\" -> \"gate:f\";
+    \"gate:f\" -> \"gate:h\" [label=\"f = 12\"];
+    \"input:y\" -> \"gate:z\" [label=\"y\"];
+    \"gate:f\" -> \"gate:z\" [label=\"f = 12\"];
+    \"gate:h\" -> \"output:h\" [label=\"h = 65523\"];
+    \"gate:z\" -> \"output:z\" [label=\"z\"];
+}
+";
+        assert_eq!(expected, circuit.to_dot(true));
+        assert!(circuit.to_dot(false).contains("[label=\"f\"]"));
+    }
+
+    /// Tests that the circuit is converted into a JSON graph of gates and wires.
+    #[test]
+    fn test_circuit_to_json() {
+        let mut circuit: Circuit = Circuit::new("3 -> x\nx LSHIFT 2 -> f\ny OR f -> z\n").unwrap();
+        circuit.value("f").unwrap();
+        let expected = json!({
+            "gates": [
+                {"output": "x", "op": "VALUE", "inputs": [3]},
+                {"output": "f", "op": "LSHIFT", "inputs": ["x", 2]},
+                {"output": "z", "op": "OR", "inputs": ["y", "f"]},
+            ],
+            "wires": [
+                {"name": "x", "driven": true, "consumers": ["f"], "value": 3},
+                {"name": "f", "driven": true, "consumers": ["z"], "value": 12},
+                {"name": "z", "driven": true, "consumers": [], "value": null},
+                {"name": "y", "driven": false, "consumers": ["z"], "value": null},
+            ],
+        });
+        assert_eq!(expected, circuit.to_json(true));
+        assert!(circuit.to_json(false)["wires"][0].get("value").is_none());
+    }
+
+    /// Tests that the signal width chosen when parsing the netlist is used for values and gates.
+    #[test]
+    fn test_circuit_word_width() {
+        let netlist = "200 -> x\nNOT x -> y\nx LSHIFT 1 -> z\n";
+        let mut circuit = Circuit::<u8>::new(netlist).unwrap();
+        assert_eq!(Ok(vec![55, 144]), circuit.values(&["y", "z"]));
+        let mut circuit = Circuit::<u32>::new(netlist).unwrap();
+        assert_eq!(Ok(vec![4294967095, 400]), circuit.values(&["y", "z"]));
+        let mut circuit = Circuit::<u64>::new("4294967296 OR 1 -> a\n").unwrap();
+        assert_eq!(Ok(4294967297), circuit.value("a"));
+        let err = Circuit::<u8>::new("256 -> x\n").err().unwrap();
+        assert_eq!(("8-bit value", "256"), (err.expected(), err.found()));
+    }
+
+    /// Tests that shifting by the signal width or more shifts out all of the bits.
+    #[test]
+    fn test_circuit_shift_beyond_width() {
+        let netlist = "65535 -> x\nx LSHIFT 16 -> a\nx RSHIFT 17 -> b\nx LSHIFT 15 -> c\n";
+        let mut circuit: Circuit = Circuit::new(netlist).unwrap();
+        assert_eq!(Ok(vec![0, 0, 32768]), circuit.values(&["a", "b", "c"]));
+        let mut circuit =
+            Circuit::<u64>::new("1 -> x\nx LSHIFT 64 -> a\nx LSHIFT 63 -> b\n").unwrap();
+        assert_eq!(Ok(vec![0, 1 << 63]), circuit.values(&["a", "b"]));
+    }
+
+    /// Tests that constant gates are folded, identity gates are replaced by their input and gates
+    /// not feeding into the output wires are removed.
+    #[test]
+    fn test_circuit_simplify() {
+        let netlist = "123 -> x\n456 -> y\nx AND y -> d\nd OR q -> e\nq LSHIFT 0 -> f\n\
+                       f AND 65535 -> g\ng OR e -> a\nNOT x -> h\ny RSHIFT 16 -> i\ni OR h -> b\n";
+        let mut circuit: Circuit = Circuit::new(netlist).unwrap();
+        let mut simplified = circuit.simplify(&["a", "b"]).unwrap();
+        assert_eq!(
+            "72 OR q -> e\nq OR e -> a\n65412 -> b\n",
+            simplified.to_netlist()
+        );
+        circuit.force("q", 5);
+        simplified.force("q", 5);
+        assert_eq!(circuit.values(&["a", "b"]), simplified.values(&["a", "b"]));
+        let simplified = circuit.simplify(&["f"]).unwrap();
+        assert_eq!("5 -> f\n", simplified.to_netlist());
+    }
+
+    /// Tests that a circuit converted back into a netlist is parsed into the same circuit.
+    #[test]
+    fn test_circuit_to_netlist() {
+        let circuit: Circuit = Circuit::new(NETLIST).unwrap();
+        assert_eq!(NETLIST, circuit.to_netlist());
+    }
+
+    /// Tests that a numeric term too large for a wire signal is reported with its location.
+    #[test]
+    fn test_circuit_parse_value_out_of_range() {
+        let err = Circuit::<u16>::new("123 -> x\nx OR 65536 -> y\n")
+            .err()
+            .unwrap();
+        assert_eq!((2, 6), (err.line(), err.column()));
+        assert_eq!("65536", err.found());
+    }
+
+    /// Tests that a feedback loop is reported with the path of wires forming the loop.
+    #[test]
+    fn test_circuit_value_cycle() {
+        let mut circuit: Circuit =
+            Circuit::new("x AND y -> a\n1 -> x\nNOT z -> y\ny OR a -> z\n").unwrap();
+        let path = ["y", "z", "y"].map(String::from).to_vec();
+        assert_eq!(Err(CircuitError::Cycle { path }), circuit.value("a"));
+    }
+
+    /// Tests that a wire without an input is reported along with the wire that requires it.
+    #[test]
+    fn test_circuit_value_missing() {
+        let mut circuit: Circuit = Circuit::new("x AND y -> a\n1 -> x\n").unwrap();
+        let err = CircuitError::MissingWire {
+            wire: String::from("y"),
+            required_by: Some(String::from("a")),
+        };
+        assert_eq!(Err(err), circuit.value("a"));
+    }
+
+    /// Tests that a very long chain of wires is evaluated without overflowing the stack.
+    #[test]
+    fn test_circuit_value_deep_chain() {
+        let names: Vec<String> = (0..200_000).map(|i| format!("w{}", i)).collect();
+        let mut circuit: Circuit = Circuit::default();
+        let left = String::from("1");
+        circuit.set_operation(&names[0], Operation::Value { left });
+        for i in 1..names.len() {
+            let left = names[i - 1].clone();
+            circuit.set_operation(&names[i], Operation::Not { left });
+        }
+        assert_eq!(Ok(!1), circuit.value(&names[names.len() - 1]));
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use super::{Circuit, CircuitError, Operation, Word};
+
+/// Represents a single gate of a compiled circuit. Each operand and result is the index of a
+/// register holding a wire signal or a specific value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Copy {
+        input: usize,
+        out: usize,
+    },
+    And {
+        left: usize,
+        right: usize,
+        out: usize,
+    },
+    LShift {
+        left: usize,
+        right: usize,
+        out: usize,
+    },
+    RShift {
+        left: usize,
+        right: usize,
+        out: usize,
+    },
+    Not {
+        input: usize,
+        out: usize,
+    },
+    Or {
+        left: usize,
+        right: usize,
+        out: usize,
+    },
+}
+
+/// Represents a circuit compiled into a flat program, with the gates needed to determine the values
+/// of the output wires sorted so that each gate comes after the gates feeding into it. Wire names
+/// are replaced by register indices, so evaluating the circuit is a single pass over the
+/// instructions.
+#[derive(Clone, Debug)]
+pub struct CircuitProgram<W: Word = u16> {
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    input_registers: Vec<usize>,
+    output_registers: Vec<usize>,
+    instructions: Vec<Instruction>,
+    registers: Vec<W>, // initial register values, with the specific values used by the gates
+}
+
+impl<W: Word> CircuitProgram<W> {
+    /// Compiles the gates of the circuit needed to determine the values of the output wires. The
+    /// values of the input wires are provided each time the program is evaluated, replacing the
+    /// operations that would otherwise provide their values. Returns an error if an output wire
+    /// cannot be evaluated.
+    pub fn compile(
+        circuit: &Circuit<W>,
+        inputs: &[&str],
+        outputs: &[&str],
+    ) -> Result<CircuitProgram<W>, CircuitError> {
+        let order = circuit.topological_order(outputs, inputs)?;
+        let mut registers: Vec<W> = vec![];
+        let mut wire_registers: HashMap<&str, usize> = HashMap::new();
+        let mut value_registers: HashMap<W, usize> = HashMap::new();
+        let input_registers: Vec<usize> = inputs
+            .iter()
+            .map(|&input| {
+                registers.push(W::default());
+                wire_registers.insert(input, registers.len() - 1);
+                registers.len() - 1
+            })
+            .collect();
+        let mut instructions: Vec<Instruction> = vec![];
+        for wire in order {
+            let op = circuit.operation(wire).unwrap();
+            if let Operation::Value { left } = op {
+                if let Ok(value) = left.parse::<W>() {
+                    // Wires provided with a specific value do not need an instruction
+                    registers.push(value);
+                    wire_registers.insert(wire, registers.len() - 1);
+                    continue;
+                }
+            }
+            registers.push(W::default());
+            let out = registers.len() - 1;
+            wire_registers.insert(wire, out);
+            // Specific values are each held in a single register, shared by all gates using them
+            let mut register = |term: &str| match term.parse::<W>() {
+                Ok(value) => *value_registers.entry(value).or_insert_with(|| {
+                    registers.push(value);
+                    registers.len() - 1
+                }),
+                Err(_) => wire_registers[term],
+            };
+            let instruction = match op {
+                Operation::Value { left } => Instruction::Copy {
+                    input: register(left),
+                    out,
+                },
+                Operation::And { left, right } => Instruction::And {
+                    left: register(left),
+                    right: register(right),
+                    out,
+                },
+                Operation::LShift { left, right } => Instruction::LShift {
+                    left: register(left),
+                    right: register(right),
+                    out,
+                },
+                Operation::RShift { left, right } => Instruction::RShift {
+                    left: register(left),
+                    right: register(right),
+                    out,
+                },
+                Operation::Not { left } => Instruction::Not {
+                    input: register(left),
+                    out,
+                },
+                Operation::Or { left, right } => Instruction::Or {
+                    left: register(left),
+                    right: register(right),
+                    out,
+                },
+            };
+            instructions.push(instruction);
+        }
+        Ok(CircuitProgram {
+            inputs: inputs.iter().map(|input| input.to_string()).collect(),
+            outputs: outputs.iter().map(|output| output.to_string()).collect(),
+            input_registers,
+            output_registers: outputs
+                .iter()
+                .map(|output| wire_registers[output])
+                .collect(),
+            instructions,
+            registers,
+        })
+    }
+
+    /// Returns the names of the input wires, in the order their values are provided.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the names of the output wires, in the order their values are returned.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Returns the instructions of the program, in evaluation order.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Determines the values of the output wires for the given values of the input wires.
+    pub fn evaluate(&self, inputs: &[W]) -> Vec<W> {
+        let mut registers = self.registers.clone();
+        self.run(&mut registers, inputs);
+        self.read_outputs(&registers)
+    }
+
+    /// Determines the values of the output wires for each of the given vectors of input wire
+    /// values, reusing the same registers for every evaluation.
+    pub fn evaluate_batch(&self, input_vectors: &[Vec<W>]) -> Vec<Vec<W>> {
+        let mut registers = self.registers.clone();
+        input_vectors
+            .iter()
+            .map(|inputs| {
+                self.run(&mut registers, inputs);
+                self.read_outputs(&registers)
+            })
+            .collect()
+    }
+
+    /// Loads the input wire values into the registers and executes each instruction in turn.
+    fn run(&self, registers: &mut [W], inputs: &[W]) {
+        assert_eq!(
+            self.input_registers.len(),
+            inputs.len(),
+            "expected a value for each of the input wires {:?}",
+            self.inputs
+        );
+        for (&register, &value) in self.input_registers.iter().zip(inputs) {
+            registers[register] = value;
+        }
+        for instruction in self.instructions.iter() {
+            match *instruction {
+                Instruction::Copy { input, out } => registers[out] = registers[input],
+                Instruction::And { left, right, out } => {
+                    registers[out] = registers[left] & registers[right]
+                }
+                Instruction::LShift { left, right, out } => {
+                    registers[out] = registers[left].shift_left(registers[right])
+                }
+                Instruction::RShift { left, right, out } => {
+                    registers[out] = registers[left].shift_right(registers[right])
+                }
+                Instruction::Not { input, out } => registers[out] = !registers[input],
+                Instruction::Or { left, right, out } => {
+                    registers[out] = registers[left] | registers[right]
+                }
+            }
+        }
+    }
+
+    /// Reads the values of the output wires from the registers.
+    fn read_outputs(&self, registers: &[W]) -> Vec<W> {
+        self.output_registers
+            .iter()
+            .map(|&register| registers[register])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the compiled program determines the same output values as the circuit, with only
+    /// the gates needed for the outputs compiled into instructions.
+    #[test]
+    fn test_circuit_program_evaluate() {
+        let netlist = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\n\
+                       y RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i\n";
+        let mut circuit: Circuit = Circuit::new(netlist).unwrap();
+        let program = circuit.compile(&[], &["h", "d", "f"]).unwrap();
+        assert_eq!(3, program.instructions().len());
+        assert_eq!(
+            circuit.values(&["h", "d", "f"]).unwrap(),
+            program.evaluate(&[])
+        );
+    }
+
+    /// Tests that the program is evaluated for many vectors of input wire values, with the input
+    /// wires replacing the operations that would otherwise provide their values.
+    #[test]
+    fn test_circuit_program_evaluate_batch() {
+        let netlist = "7 -> x\nx AND y -> d\nx OR y -> e\nd LSHIFT 2 -> f\nNOT f -> g\n";
+        let circuit: Circuit<u8> = Circuit::new(netlist).unwrap();
+        let program = circuit.compile(&["x", "y"], &["d", "e", "g"]).unwrap();
+        let input_vectors = vec![vec![12, 10], vec![0, 255], vec![255, 255]];
+        let expected = vec![vec![8, 14, 223], vec![0, 255, 255], vec![255, 255, 3]];
+        assert_eq!(expected, program.evaluate_batch(&input_vectors));
+        assert_eq!(&["x", "y"], program.inputs());
+        // Wires without an input must be provided as input wires
+        assert!(circuit.compile(&["x"], &["d"]).is_err());
+    }
+
+    /// Tests that a very long chain of gates is compiled and evaluated.
+    #[test]
+    fn test_circuit_program_deep_chain() {
+        let names: Vec<String> = (0..200_000).map(|i| format!("w{}", i)).collect();
+        let mut circuit: Circuit = Circuit::default();
+        for i in 1..names.len() {
+            let left = names[i - 1].clone();
+            circuit.set_operation(&names[i], Operation::Not { left });
+        }
+        let program = circuit
+            .compile(&["w0"], &[&names[names.len() - 1]])
+            .unwrap();
+        assert_eq!(names.len() - 1, program.instructions().len());
+        assert_eq!(
+            vec![vec![!5], vec![!9]],
+            program.evaluate_batch(&[vec![5], vec![9]])
+        );
+    }
+}
@@ -1,3 +1,5 @@
+mod circuit;
+mod circuitprogram;
 mod ingredient;
 mod magicentity;
 mod passwordgenerator;
@@ -7,7 +9,10 @@ mod rpgentity;
 mod rpgitem;
 mod simplecomputer;
 mod spell;
+mod stringcodec;
 
+pub use circuit::{Circuit, CircuitError, Operation, Word};
+pub use circuitprogram::{CircuitProgram, Instruction};
 pub use ingredient::Ingredient;
 pub use magicentity::MagicEntity;
 pub use passwordgenerator::PasswordGenerator;
@@ -17,3 +22,4 @@ pub use rpgentity::RpgEntity;
 pub use rpgitem::RpgItem;
 pub use simplecomputer::SimpleComputer;
 pub use spell::Spell;
+pub use stringcodec::{EscapeDialect, LengthReport, LiteralError, StringCodec};
@@ -1,6 +1,8 @@
 use fancy_regex::Regex;
 use lazy_static::lazy_static;
 
+use crate::utils::{parse_capture, ParseError};
+
 lazy_static! {
     static ref REGEX_HLF: Regex = Regex::new(r"^hlf (a|b)$").unwrap();
     static ref REGEX_TPL: Regex = Regex::new(r"^tpl (a|b)$").unwrap();
@@ -31,9 +33,14 @@ pub struct SimpleComputer {
 }
 
 impl SimpleComputer {
-    pub fn new(raw_input: &str, register_a: isize, register_b: isize) -> Option<SimpleComputer> {
+    pub fn new(
+        raw_input: &str,
+        register_a: isize,
+        register_b: isize,
+    ) -> Result<SimpleComputer, ParseError> {
         let mut instructions: Vec<Instruction> = vec![];
-        for line in raw_input.lines() {
+        for (i, line) in raw_input.lines().enumerate() {
+            let column = line.len() - line.trim_start().len() + 1;
             let line = line.trim();
             let instruction = {
                 if let Ok(Some(caps)) = REGEX_HLF.captures(line) {
@@ -46,24 +53,25 @@ impl SimpleComputer {
                     let register = caps[1].chars().next().unwrap();
                     Instruction::Increment { register }
                 } else if let Ok(Some(caps)) = REGEX_JMP.captures(line) {
-                    let offset = caps[1].parse::<isize>().unwrap();
+                    let offset = parse_capture::<isize>(&caps, 1, i + 1, column, "offset")?;
                     Instruction::Jump { offset }
                 } else if let Ok(Some(caps)) = REGEX_JIE.captures(line) {
                     let register = caps[1].chars().next().unwrap();
-                    let offset = caps[2].parse::<isize>().unwrap();
+                    let offset = parse_capture::<isize>(&caps, 2, i + 1, column, "offset")?;
                     Instruction::JumpIfEven { register, offset }
                 } else if let Ok(Some(caps)) = REGEX_JIO.captures(line) {
                     let register = caps[1].chars().next().unwrap();
-                    let offset = caps[2].parse::<isize>().unwrap();
+                    let offset = parse_capture::<isize>(&caps, 2, i + 1, column, "offset")?;
                     Instruction::JumpIfOne { register, offset }
                 } else {
                     // Invalid instruction, so the simple computer cannot be created
-                    return None;
+                    let expected = "hlf, tpl, inc, jmp, jie or jio instruction";
+                    return Err(ParseError::new(i + 1, column, expected, line));
                 }
             };
             instructions.push(instruction);
         }
-        Some(SimpleComputer {
+        Ok(SimpleComputer {
             instructions,
             pc: 0,
             register_a,
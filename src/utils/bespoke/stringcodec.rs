@@ -0,0 +1,533 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the malformed parts of a string literal that can be found while decoding it. Each
+/// position is the byte offset of the problem within the literal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LiteralError {
+    /// The literal is missing its opening (position 0) or closing (position at end) double quote.
+    MissingQuote { position: usize },
+    /// A double quote within the literal is not escaped.
+    UnescapedQuote { position: usize },
+    /// The literal ends with a backslash that escapes nothing, or escapes the closing quote.
+    TrailingBackslash { position: usize },
+    /// A hexadecimal escape is not followed by two hexadecimal digits, or gives a value that is
+    /// out of range for the dialect.
+    InvalidHexEscape { position: usize, found: String },
+    /// A unicode escape is malformed or does not give a valid character.
+    InvalidUnicodeEscape { position: usize, found: String },
+    /// A backslash is followed by a character that does not start an escape sequence.
+    UnknownEscape { position: usize, found: String },
+}
+
+impl LiteralError {
+    /// Returns the byte offset of the problem within the literal.
+    pub fn position(&self) -> usize {
+        match self {
+            LiteralError::MissingQuote { position }
+            | LiteralError::UnescapedQuote { position }
+            | LiteralError::TrailingBackslash { position }
+            | LiteralError::InvalidHexEscape { position, .. }
+            | LiteralError::InvalidUnicodeEscape { position, .. }
+            | LiteralError::UnknownEscape { position, .. } => *position,
+        }
+    }
+
+    /// Returns a description of what was expected at the position of the problem.
+    pub fn expected(&self) -> &'static str {
+        match self {
+            LiteralError::MissingQuote { .. } => "double quote",
+            LiteralError::UnescapedQuote { .. } => "escaped double quote",
+            LiteralError::TrailingBackslash { .. } => "escape sequence",
+            LiteralError::InvalidHexEscape { .. } => "valid hex escape",
+            LiteralError::InvalidUnicodeEscape { .. } => "valid unicode escape",
+            LiteralError::UnknownEscape { .. } => "known escape sequence",
+        }
+    }
+
+    /// Returns the text found at the position of the problem.
+    pub fn found(&self) -> &str {
+        match self {
+            LiteralError::MissingQuote { .. } => "",
+            LiteralError::UnescapedQuote { .. } => "\"",
+            LiteralError::TrailingBackslash { .. } => "\\",
+            LiteralError::InvalidHexEscape { found, .. }
+            | LiteralError::InvalidUnicodeEscape { found, .. }
+            | LiteralError::UnknownEscape { found, .. } => found,
+        }
+    }
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiteralError::MissingQuote { position } => {
+                write!(f, "missing double quote at byte {}", position)
+            }
+            LiteralError::UnescapedQuote { position } => {
+                write!(f, "unescaped double quote at byte {}", position)
+            }
+            LiteralError::TrailingBackslash { position } => {
+                write!(f, "trailing backslash at byte {}", position)
+            }
+            LiteralError::InvalidHexEscape { position, found } => {
+                write!(f, "invalid hex escape \"{}\" at byte {}", found, position)
+            }
+            LiteralError::InvalidUnicodeEscape { position, found } => {
+                write!(
+                    f,
+                    "invalid unicode escape \"{}\" at byte {}",
+                    found, position
+                )
+            }
+            LiteralError::UnknownEscape { position, found } => {
+                write!(f, "unknown escape \"{}\" at byte {}", found, position)
+            }
+        }
+    }
+}
+
+impl Error for LiteralError {}
+
+/// Represents the sets of escape sequences that can be used within string literals.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscapeDialect {
+    /// `\\`, `\"` and `\xHH` for any byte, as used in the AOC 2015 Day 08 problem.
+    Aoc2015,
+    /// `\\`, `\"`, `\'`, `\n`, `\r`, `\t`, `\0`, `\xHH` up to `\x7f` and `\u{H...}`, as used in
+    /// Rust string literals.
+    Rust,
+    /// `\\`, `\"`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uHHHH` (with surrogate pairs), as used
+    /// in JSON strings.
+    Json,
+}
+
+impl FromStr for EscapeDialect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aoc2015" | "aoc" => Ok(EscapeDialect::Aoc2015),
+            "rust" => Ok(EscapeDialect::Rust),
+            "json" => Ok(EscapeDialect::Json),
+            _ => Err(format!("unknown escape dialect: \"{}\"", s)),
+        }
+    }
+}
+
+impl EscapeDialect {
+    /// Returns the byte represented by the given single-character escape (the character after the
+    /// backslash), if the dialect has the escape.
+    fn simple_escape(&self, escaped: char) -> Option<u8> {
+        match (self, escaped) {
+            (_, '\\') => Some(b'\\'),
+            (_, '"') => Some(b'"'),
+            (EscapeDialect::Rust, '\'') => Some(b'\''),
+            (EscapeDialect::Rust, '0') => Some(0),
+            (EscapeDialect::Json, '/') => Some(b'/'),
+            (EscapeDialect::Json, 'b') => Some(0x08),
+            (EscapeDialect::Json, 'f') => Some(0x0c),
+            (EscapeDialect::Rust | EscapeDialect::Json, 'n') => Some(b'\n'),
+            (EscapeDialect::Rust | EscapeDialect::Json, 'r') => Some(b'\r'),
+            (EscapeDialect::Rust | EscapeDialect::Json, 't') => Some(b'\t'),
+            _ => None,
+        }
+    }
+}
+
+/// Lengths of a string literal and of the string it represents.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LengthReport {
+    /// Number of bytes in the literal, including the double quotes.
+    pub code_bytes: usize,
+    /// Number of bytes in the decoded string.
+    pub decoded_bytes: usize,
+    /// Number of characters in the decoded string, with each byte that is not part of a valid
+    /// UTF-8 sequence counted as one character.
+    pub decoded_chars: usize,
+}
+
+/// Converts between the bytes of a string and double-quoted string literals, such as those used in
+/// the AOC 2015 Day 08 problem (<https://adventofcode.com/2015/day/8>). The escape sequences that
+/// can be used within the literals are determined by the escape dialect.
+#[derive(Clone, Copy, Debug)]
+pub struct StringCodec {
+    dialect: EscapeDialect,
+}
+
+impl Default for StringCodec {
+    fn default() -> Self {
+        StringCodec::new(EscapeDialect::Aoc2015)
+    }
+}
+
+impl StringCodec {
+    pub const fn new(dialect: EscapeDialect) -> StringCodec {
+        StringCodec { dialect }
+    }
+
+    pub fn dialect(&self) -> EscapeDialect {
+        self.dialect
+    }
+
+    /// Decodes the given string literal into the bytes it represents. Returns every malformed part
+    /// of the literal if it cannot be decoded.
+    pub fn decode(&self, literal: &str) -> Result<Vec<u8>, Vec<LiteralError>> {
+        let bytes = literal.as_bytes();
+        let mut decoded: Vec<u8> = vec![];
+        let mut errors: Vec<LiteralError> = vec![];
+        let mut i = match bytes.first() {
+            Some(b'"') => 1,
+            _ => {
+                errors.push(LiteralError::MissingQuote { position: 0 });
+                0
+            }
+        };
+        let mut is_closed = false;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'"' if i == bytes.len() - 1 => {
+                    is_closed = true;
+                    i += 1;
+                }
+                b'"' => {
+                    errors.push(LiteralError::UnescapedQuote { position: i });
+                    decoded.push(b'"');
+                    i += 1;
+                }
+                b'\\' => {
+                    let escape_len = self.decode_escape(literal, i, &mut decoded, &mut errors);
+                    if escape_len == 0 {
+                        // Backslash escapes nothing, so the literal cannot be closed
+                        is_closed = true;
+                        break;
+                    }
+                    i += escape_len;
+                }
+                byte => {
+                    decoded.push(byte);
+                    i += 1;
+                }
+            }
+        }
+        if !is_closed {
+            errors.push(LiteralError::MissingQuote {
+                position: bytes.len(),
+            });
+        }
+        if errors.is_empty() {
+            Ok(decoded)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Encodes the given bytes into a string literal. Backslashes, double quotes and control
+    /// characters are escaped. In the AOC 2015 dialect all other bytes that are not printable ASCII
+    /// characters are escaped, so decoding the literal always gives the same bytes. The other
+    /// dialects keep non-ASCII characters as they are, and replace bytes that are not part of a
+    /// valid UTF-8 sequence with U+FFFD, so decoding gives the same bytes for any valid UTF-8.
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        let mut literal = String::from("\"");
+        if self.dialect == EscapeDialect::Aoc2015 {
+            for &byte in bytes {
+                match byte {
+                    b'\\' => literal.push_str("\\\\"),
+                    b'"' => literal.push_str("\\\""),
+                    b' '..=b'~' => literal.push(byte as char),
+                    _ => literal.push_str(&format!("\\x{:02x}", byte)),
+                }
+            }
+        } else {
+            for chunk in bytes.utf8_chunks() {
+                for c in chunk.valid().chars() {
+                    self.encode_char(c, &mut literal);
+                }
+                if !chunk.invalid().is_empty() {
+                    self.encode_char(char::REPLACEMENT_CHARACTER, &mut literal);
+                }
+            }
+        }
+        literal.push('"');
+        literal
+    }
+
+    /// Determines the lengths of the given string literal and the string it represents. Returns
+    /// every malformed part of the literal if it cannot be decoded.
+    pub fn lengths(&self, literal: &str) -> Result<LengthReport, Vec<LiteralError>> {
+        let decoded = self.decode(literal)?;
+        Ok(LengthReport {
+            code_bytes: literal.len(),
+            decoded_bytes: decoded.len(),
+            decoded_chars: String::from_utf8_lossy(&decoded).chars().count(),
+        })
+    }
+
+    /// Adds the given character to the literal, escaped as needed in the Rust or JSON dialect.
+    fn encode_char(&self, c: char, literal: &mut String) {
+        match (self.dialect, c) {
+            (_, '\\') => literal.push_str("\\\\"),
+            (_, '"') => literal.push_str("\\\""),
+            (_, '\n') => literal.push_str("\\n"),
+            (_, '\r') => literal.push_str("\\r"),
+            (_, '\t') => literal.push_str("\\t"),
+            (EscapeDialect::Json, c) if c.is_control() => {
+                literal.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            (_, c) if c.is_ascii_control() => literal.push_str(&format!("\\x{:02x}", c as u32)),
+            (_, c) if c.is_control() => literal.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            (_, c) => literal.push(c),
+        }
+    }
+
+    /// Decodes the escape sequence starting with the backslash at the given position of the
+    /// literal, adding the decoded bytes or any error found. Returns the length of the escape
+    /// sequence, or 0 if the backslash is trailing.
+    fn decode_escape(
+        &self,
+        literal: &str,
+        position: usize,
+        decoded: &mut Vec<u8>,
+        errors: &mut Vec<LiteralError>,
+    ) -> usize {
+        let rest = &literal[position + 1..];
+        let Some(escaped) = rest.chars().next().filter(|_| rest != "\"") else {
+            errors.push(LiteralError::TrailingBackslash { position });
+            return 0;
+        };
+        if let Some(byte) = self.dialect.simple_escape(escaped) {
+            decoded.push(byte);
+            return 2;
+        }
+        let (result, escape_len) = match (self.dialect, escaped) {
+            (EscapeDialect::Aoc2015 | EscapeDialect::Rust, 'x') => self.decode_hex(rest),
+            (EscapeDialect::Rust, 'u') => decode_rust_unicode(rest),
+            (EscapeDialect::Json, 'u') => decode_json_unicode(rest),
+            _ => {
+                let found = format!("\\{}", escaped);
+                errors.push(LiteralError::UnknownEscape { position, found });
+                return 1 + escaped.len_utf8();
+            }
+        };
+        let found = literal[position..position + escape_len].to_string();
+        match result {
+            Some(Decoded::Byte(byte)) => decoded.push(byte),
+            Some(Decoded::Char(c)) => decoded.extend(c.to_string().as_bytes()),
+            None if escaped == 'x' => {
+                errors.push(LiteralError::InvalidHexEscape { position, found })
+            }
+            None => errors.push(LiteralError::InvalidUnicodeEscape { position, found }),
+        }
+        escape_len
+    }
+
+    /// Decodes a hex escape from the text following its backslash. Returned value is the decoded
+    /// byte (None if the escape is malformed or out of range) and the length of the escape.
+    fn decode_hex(&self, rest: &str) -> (Option<Decoded>, usize) {
+        let digits = hex_digits(&rest[1..], 2);
+        let max = match self.dialect {
+            EscapeDialect::Rust => 0x7f,
+            _ => 0xff,
+        };
+        let byte = match digits {
+            2 => u8::from_str_radix(&rest[1..3], 16)
+                .ok()
+                .filter(|b| *b <= max),
+            _ => None,
+        };
+        (byte.map(Decoded::Byte), 2 + digits)
+    }
+}
+
+/// Represents the value given by an escape sequence.
+enum Decoded {
+    Byte(u8),
+    Char(char),
+}
+
+/// Counts the hexadecimal digits (up to the given limit) at the start of the text.
+fn hex_digits(text: &str, limit: usize) -> usize {
+    text.bytes()
+        .take(limit)
+        .take_while(|b| b.is_ascii_hexdigit())
+        .count()
+}
+
+/// Decodes a Rust unicode escape (`\u{H...}` with one to six digits) from the text following its
+/// backslash. Returned value is the decoded character (None if malformed or not a valid character)
+/// and the length of the escape.
+fn decode_rust_unicode(rest: &str) -> (Option<Decoded>, usize) {
+    if !rest[1..].starts_with('{') {
+        return (None, 2);
+    }
+    let digits = hex_digits(&rest[2..], 7);
+    if !rest[2 + digits..].starts_with('}') {
+        return (None, 3 + digits);
+    }
+    let c = match digits {
+        1..=6 => u32::from_str_radix(&rest[2..2 + digits], 16)
+            .ok()
+            .and_then(char::from_u32),
+        _ => None,
+    };
+    (c.map(Decoded::Char), 4 + digits)
+}
+
+/// Decodes a JSON unicode escape (`\uHHHH`, with characters outside the basic multilingual plane
+/// given as a surrogate pair of escapes) from the text following its backslash. Returned value is
+/// the decoded character (None if malformed or an unpaired surrogate) and the length of the escape.
+fn decode_json_unicode(rest: &str) -> (Option<Decoded>, usize) {
+    let code_unit = |text: &str| match hex_digits(text, 4) {
+        4 => Ok(u32::from_str_radix(&text[..4], 16).unwrap()),
+        digits => Err(digits),
+    };
+    let high = match code_unit(&rest[1..]) {
+        Ok(high) => high,
+        Err(digits) => return (None, 2 + digits),
+    };
+    if !(0xd800..0xdc00).contains(&high) {
+        return (char::from_u32(high).map(Decoded::Char), 6);
+    }
+    // High surrogate must be followed by an escaped low surrogate
+    match rest[5..].strip_prefix("\\u").map(code_unit) {
+        Some(Ok(low)) if (0xdc00..0xe000).contains(&low) => {
+            let c = char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00));
+            (c.map(Decoded::Char), 12)
+        }
+        _ => (None, 6),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const AOC: StringCodec = StringCodec::new(EscapeDialect::Aoc2015);
+    const RUST: StringCodec = StringCodec::new(EscapeDialect::Rust);
+    const JSON: StringCodec = StringCodec::new(EscapeDialect::Json);
+
+    /// Tests that string literals are decoded into the bytes they represent.
+    #[test]
+    fn test_string_codec_decode() {
+        assert_eq!(Ok(vec![]), AOC.decode("\"\""));
+        assert_eq!(Ok(b"aaa\"aaa".to_vec()), AOC.decode("\"aaa\\\"aaa\""));
+        assert_eq!(Ok(b"'\\\xff".to_vec()), AOC.decode("\"\\x27\\\\\\xFF\""));
+    }
+
+    /// Tests that the escapes of the Rust and JSON dialects are decoded, including UTF-8 content.
+    #[test]
+    fn test_string_codec_decode_dialects() {
+        let expected = "tab\there\n\u{1F600} é/'\0".as_bytes().to_vec();
+        let literal = "\"tab\\there\\n\\u{1F600} é/\\'\\0\"";
+        assert_eq!(Ok(expected.clone()), RUST.decode(literal));
+        let literal = "\"tab\\there\\n\\ud83d\\ude00 \\u00e9\\/'\\u0000\"";
+        assert_eq!(Ok(expected), JSON.decode(literal));
+        let found = String::from("\\n");
+        let err = LiteralError::UnknownEscape { position: 1, found };
+        assert_eq!(Err(vec![err]), AOC.decode("\"\\n\""));
+    }
+
+    /// Tests that each escape dialect is parsed from its name, with unknown names rejected.
+    #[test]
+    fn test_escape_dialect_from_str() {
+        assert_eq!(Ok(EscapeDialect::Aoc2015), "aoc2015".parse());
+        assert_eq!(Ok(EscapeDialect::Aoc2015), "aoc".parse());
+        assert_eq!(Ok(EscapeDialect::Rust), "rust".parse());
+        assert_eq!(Ok(EscapeDialect::Json), "json".parse());
+        assert_eq!(
+            Err(String::from("unknown escape dialect: \"c\"")),
+            "c".parse::<EscapeDialect>()
+        );
+    }
+
+    /// Tests that decoding the encoding of any bytes gives the same bytes, and that decoding the
+    /// encoding of any valid UTF-8 gives the same string in every dialect.
+    #[test]
+    fn test_string_codec_round_trip() {
+        let bytes: Vec<u8> = (0..=255).chain(b"\\\"x27\\x".iter().copied()).collect();
+        assert_eq!(Ok(bytes.clone()), AOC.decode(&AOC.encode(&bytes)));
+        assert_eq!("\"\\\"abc\\\\x27\\\"\"", AOC.encode(b"\"abc\\x27\""));
+        let s: String = (0..0x800)
+            .filter_map(char::from_u32)
+            .chain("\u{1F600}\u{10FFFF}\\\"".chars())
+            .collect();
+        for codec in [AOC, RUST, JSON] {
+            assert_eq!(
+                Ok(s.as_bytes().to_vec()),
+                codec.decode(&codec.encode(s.as_bytes()))
+            );
+        }
+        assert_eq!(
+            "\"\\u001b\u{1F600}\\\\\"",
+            JSON.encode("\u{1b}\u{1F600}\\".as_bytes())
+        );
+        assert_eq!(
+            "\"\\x1b\u{1F600}\\u{85}\"",
+            RUST.encode("\u{1b}\u{1F600}\u{85}".as_bytes())
+        );
+        assert_eq!("\"a\u{FFFD}\"", RUST.encode(b"a\xff"));
+    }
+
+    /// Tests that the lengths of a literal are split into code bytes, decoded bytes and decoded
+    /// characters.
+    #[test]
+    fn test_string_codec_lengths() {
+        let expected = LengthReport {
+            code_bytes: 16,
+            decoded_bytes: 8,
+            decoded_chars: 4,
+        };
+        assert_eq!(Ok(expected), RUST.lengths("\"a\\u{1F600}é\\n\""));
+        let expected = LengthReport {
+            code_bytes: 10,
+            decoded_bytes: 2,
+            decoded_chars: 2,
+        };
+        assert_eq!(Ok(expected), AOC.lengths("\"\\xff\\x27\""));
+    }
+
+    /// Tests that malformed parts of a literal are reported with their positions.
+    #[test]
+    fn test_string_codec_decode_errors() {
+        let errors = vec![
+            LiteralError::InvalidHexEscape {
+                position: 3,
+                found: String::from("\\x4"),
+            },
+            LiteralError::UnescapedQuote { position: 7 },
+            LiteralError::UnknownEscape {
+                position: 9,
+                found: String::from("\\n"),
+            },
+        ];
+        assert_eq!(Err(errors), AOC.decode("\"ab\\x4g\"h\\n\""));
+        let errors = vec![LiteralError::TrailingBackslash { position: 4 }];
+        assert_eq!(Err(errors), AOC.decode("\"abc\\\""));
+        let errors = vec![
+            LiteralError::MissingQuote { position: 0 },
+            LiteralError::TrailingBackslash { position: 3 },
+        ];
+        assert_eq!(Err(errors), AOC.decode("abc\\"));
+        let errors = vec![
+            LiteralError::InvalidHexEscape {
+                position: 1,
+                found: String::from("\\x80"),
+            },
+            LiteralError::InvalidUnicodeEscape {
+                position: 5,
+                found: String::from("\\u{d800}"),
+            },
+            LiteralError::InvalidUnicodeEscape {
+                position: 13,
+                found: String::from("\\u{1234567}"),
+            },
+        ];
+        assert_eq!(Err(errors), RUST.decode("\"\\x80\\u{d800}\\u{1234567}\""));
+        let errors = vec![LiteralError::InvalidUnicodeEscape {
+            position: 1,
+            found: String::from("\\ud83d"),
+        }];
+        assert_eq!(Err(errors), JSON.decode("\"\\ud83dx\""));
+    }
+}
@@ -1 +1,6 @@
 pub mod bespoke;
+mod parseerror;
+mod random;
+
+pub use parseerror::{parse_capture, ParseError};
+pub use random::Prng;
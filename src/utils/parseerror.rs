@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use fancy_regex::Captures;
+
+/// Represents a failure to process a problem input into the format required by the solver
+/// functions. Line and column numbers are 1-based, with a line number of 0 used for errors that are
+/// not tied to a location within the input (e.g. the input file could not be read).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<String>,
+    line: usize,
+    column: usize,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// Creates a new ParseError for the given byte offset into the raw input, with the line and
+    /// column numbers determined from the offset.
+    pub fn at_offset(raw_input: &str, offset: usize, expected: &str, found: &str) -> ParseError {
+        let preceding = &raw_input[..offset];
+        let line = preceding.matches('\n').count() + 1;
+        let column = match preceding.rfind('\n') {
+            Some(i) => preceding[i + 1..].chars().count() + 1,
+            None => preceding.chars().count() + 1,
+        };
+        ParseError::new(line, column, expected, found)
+    }
+
+    /// Creates a new ParseError for an input file that could not be read.
+    pub fn unreadable(filename: &str, err: &io::Error) -> ParseError {
+        ParseError {
+            file: Some(filename.to_string()),
+            line: 0,
+            column: 0,
+            expected: String::from("readable input file"),
+            found: err.to_string(),
+        }
+    }
+
+    /// Returns the ParseError with the name of the input file the error was found in.
+    pub fn with_file(mut self, filename: &str) -> ParseError {
+        self.file = Some(filename.to_string());
+        self
+    }
+
+    /// Returns the name of the input file the error was found in, if known.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        if self.line == 0 {
+            return write!(
+                f,
+                "{}: expected {}, found: {}",
+                file, self.expected, self.found
+            );
+        }
+        write!(
+            f,
+            "{}:{}:{}: expected {}, found \"{}\"",
+            file, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses the text matched by the given regex capture group into the requested type. The column
+/// number given is that of the start of the text the regex was matched against.
+pub fn parse_capture<T: FromStr>(
+    caps: &Captures,
+    group: usize,
+    line: usize,
+    column: usize,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let m = caps.get(group).unwrap();
+    m.as_str()
+        .parse::<T>()
+        .map_err(|_| ParseError::new(line, column + m.start(), expected, m.as_str()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the line and column numbers are determined correctly from a byte offset.
+    #[test]
+    fn test_parse_error_at_offset() {
+        let err = ParseError::at_offset("abc\ndef\nghi", 9, "letter", "h");
+        assert_eq!((3, 2), (err.line(), err.column()));
+        let err = ParseError::at_offset("abc", 0, "letter", "a");
+        assert_eq!((1, 1), (err.line(), err.column()));
+    }
+
+    /// Tests the formatting of the error message when the input file is known.
+    #[test]
+    fn test_parse_error_display() {
+        let err = ParseError::new(7, 3, "distance", "x").with_file("./input/day09.txt");
+        assert_eq!(
+            "./input/day09.txt:7:3: expected distance, found \"x\"",
+            err.to_string()
+        );
+    }
+}
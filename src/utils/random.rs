@@ -0,0 +1,83 @@
+/// Pseudorandom number generator for the heuristic solvers, using the xorshift64* algorithm. The
+/// same seed always produces the same sequence of numbers, so heuristic runs can be reproduced. Not
+/// suitable for anything needing unpredictable numbers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Prng {
+    state: u64,
+}
+
+impl Prng {
+    /// Creates a new Prng from the given seed. Any seed (including 0) is valid, with the seed mixed
+    /// using the SplitMix64 finaliser so similar seeds give unrelated sequences.
+    pub fn new(seed: u64) -> Prng {
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        // The xorshift state must never be zero
+        Prng {
+            state: if z == 0 { 0x9e37_79b9_7f4a_7c15 } else { z },
+        }
+    }
+
+    /// Returns the next number in the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns a number in the range 0 to bound (exclusive). Panics if the bound is 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound must be greater than 0");
+        // Multiplying by the bound avoids the bias towards small numbers of taking the remainder
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Returns a number in the range 0.0 to 1.0 (exclusive).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the items into a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the same seed gives the same sequence and different seeds give different ones.
+    #[test]
+    fn test_prng_seeded_sequence() {
+        let sequence = |seed: u64| {
+            let mut prng = Prng::new(seed);
+            (0..8).map(|_| prng.next_u64()).collect::<Vec<u64>>()
+        };
+        assert_eq!(sequence(42), sequence(42));
+        assert_ne!(sequence(0), sequence(1));
+        assert!(sequence(0).iter().all(|&n| n != 0));
+    }
+
+    /// Tests that bounded numbers stay within their bounds and cover the whole range.
+    #[test]
+    fn test_prng_bounds() {
+        let mut prng = Prng::new(7);
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[prng.below(6)] += 1;
+        }
+        assert!(counts.iter().all(|&count| count > 800 && count < 1200));
+        assert!((0..1000).all(|_| (0.0..1.0).contains(&prng.next_f64())));
+        let mut items: Vec<usize> = (0..10).collect();
+        prng.shuffle(&mut items);
+        assert_ne!((0..10).collect::<Vec<usize>>(), items);
+        items.sort();
+        assert_eq!((0..10).collect::<Vec<usize>>(), items);
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use md5::{Digest, Md5};
+use serde_json::{json, Map, Value};
+
+use crate::runner::DayResult;
+use crate::solution::Answer;
+
+/// Default location of the expected answers file.
+pub const DEFAULT_ANSWERS_FILE: &str = "./answers.json";
+
+/// Calculates the key used to look up the expected answers for a raw problem input, being the
+/// hexadecimal representation of the MD5 hash of the input.
+pub fn input_hash(raw_input: &str) -> String {
+    let mut hasher = Md5::new();
+    hasher.update(raw_input.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Expected answers for the input of a single day. Answers are held as their string
+/// representation, so they can be compared against answers of any native type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub day: u64,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Collection of expected answers keyed by the hash of the input they were solved for. Loaded from
+/// a JSON file of the form:
+///
+/// `{"<input md5>": {"day": 7, "part1": 956, "part2": 40149}, ...}`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerKey {
+    entries: BTreeMap<String, ExpectedAnswers>,
+}
+
+impl AnswerKey {
+    /// Reads the answer key from the given JSON file.
+    pub fn read(filename: &str) -> Result<AnswerKey, String> {
+        let raw = fs::read_to_string(filename).map_err(|err| format!("{filename}: {err}"))?;
+        let json: Value = serde_json::from_str(&raw).map_err(|err| format!("{filename}: {err}"))?;
+        AnswerKey::from_json(&json).map_err(|msg| format!("{filename}: {msg}"))
+    }
+
+    /// Converts the contents of an answers file into an answer key.
+    pub fn from_json(json: &Value) -> Result<AnswerKey, String> {
+        let Some(object) = json.as_object() else {
+            return Err(String::from("expected an object keyed by input hash"));
+        };
+        let mut entries: BTreeMap<String, ExpectedAnswers> = BTreeMap::new();
+        for (hash, entry) in object.iter() {
+            let Some(day) = entry["day"].as_u64() else {
+                return Err(format!("missing day number for input \"{hash}\""));
+            };
+            let answer = |part: &str| match &entry[part] {
+                Value::Null => Ok(None),
+                Value::String(s) => Ok(Some(s.to_string())),
+                Value::Number(n) => Ok(Some(n.to_string())),
+                _ => Err(format!("invalid {part} answer for input \"{hash}\"")),
+            };
+            let expected = ExpectedAnswers {
+                day,
+                part1: answer("part1")?,
+                part2: answer("part2")?,
+            };
+            entries.insert(hash.to_string(), expected);
+        }
+        Ok(AnswerKey { entries })
+    }
+
+    /// Converts the answer key into the JSON format used by the answers file.
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        for (hash, expected) in self.entries.iter() {
+            let mut entry = Map::new();
+            entry.insert(String::from("day"), json!(expected.day));
+            if let Some(answer) = &expected.part1 {
+                entry.insert(String::from("part1"), answer_to_json(answer));
+            }
+            if let Some(answer) = &expected.part2 {
+                entry.insert(String::from("part2"), answer_to_json(answer));
+            }
+            object.insert(hash.to_string(), Value::Object(entry));
+        }
+        Value::Object(object)
+    }
+
+    /// Writes the answer key to the given file as JSON.
+    pub fn write(&self, filename: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.to_json()).map_err(|err| err.to_string())?;
+        fs::write(filename, json + "\n").map_err(|err| format!("{filename}: {err}"))
+    }
+
+    /// Returns the expected answers for the given day and input hash, if known.
+    pub fn get(&self, day: u64, hash: &str) -> Option<&ExpectedAnswers> {
+        self.entries
+            .get(hash)
+            .filter(|expected| expected.day == day)
+    }
+
+    /// Records the answers from the given result for any parts that do not already have an
+    /// expected answer. Returns the number of answers recorded.
+    pub fn record(&mut self, result: &DayResult) -> usize {
+        let new_entry = ExpectedAnswers {
+            day: result.day,
+            part1: None,
+            part2: None,
+        };
+        let expected = self
+            .entries
+            .entry(result.input_hash.to_string())
+            .or_insert(new_entry);
+        if expected.day != result.day {
+            return 0;
+        }
+        let mut recorded = 0;
+        for (expected, answer) in [
+            (&mut expected.part1, &result.part1),
+            (&mut expected.part2, &result.part2),
+        ] {
+            if let (None, Some(answer)) = (&expected, answer) {
+                *expected = Some(answer.to_string());
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+}
+
+/// Converts a stored answer back into JSON, using a number if the answer is an integer that can be
+/// represented exactly.
+fn answer_to_json(answer: &str) -> Value {
+    if let Ok(value) = answer.parse::<u64>() {
+        return json!(value);
+    }
+    if let Ok(value) = answer.parse::<i64>() {
+        return json!(value);
+    }
+    json!(answer)
+}
+
+/// Represents the outcome of checking the answer for one part against the expected answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Verdict {
+    /// Checks the answer against the expected answer, if there is one.
+    pub fn check(answer: &Answer, expected: Option<&str>) -> Verdict {
+        match expected {
+            None => Verdict::Missing,
+            Some(expected) if answer.to_string() == expected => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    /// Returns the label used when reporting the verdict.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Missing => "MISSING",
+        }
+    }
+}
+
+/// Holds the verdicts for the parts of a single day that were solved.
+pub struct DayVerification<'a> {
+    pub result: &'a DayResult,
+    pub part1: Option<Verdict>,
+    pub part2: Option<Verdict>,
+}
+
+impl DayVerification<'_> {
+    /// Checks if any of the solved parts did not have an expected answer.
+    pub fn has_missing(&self) -> bool {
+        [&self.part1, &self.part2]
+            .iter()
+            .any(|verdict| matches!(verdict, Some(Verdict::Missing)))
+    }
+
+    /// Prints the verdict for each solved part to stdout.
+    pub fn print(&self) {
+        let parts = [
+            (1, &self.part1, &self.result.part1),
+            (2, &self.part2, &self.result.part2),
+        ];
+        for (part, verdict, answer) in parts {
+            let (Some(verdict), Some(answer)) = (verdict, answer) else {
+                continue;
+            };
+            let marker = match verdict {
+                Verdict::Pass => "[+]",
+                Verdict::Fail { .. } => "[!]",
+                Verdict::Missing => "[*]",
+            };
+            print!(
+                "{} {:<7} Day {:02} Part {} - {}",
+                marker,
+                verdict.label(),
+                self.result.day,
+                part,
+                answer
+            );
+            if let Verdict::Fail { expected } = verdict {
+                print!(" (expected {})", expected);
+            }
+            if !self.result.is_default_input {
+                print!(" // {}", self.result.input);
+            }
+            println!();
+        }
+    }
+}
+
+/// Checks the answers in the given result against the expected answers for its input.
+pub fn verify_result<'a>(result: &'a DayResult, key: &AnswerKey) -> DayVerification<'a> {
+    let expected = key.get(result.day, &result.input_hash);
+    let verdict = |answer: &Option<Answer>, expected: Option<&Option<String>>| {
+        answer
+            .as_ref()
+            .map(|answer| Verdict::check(answer, expected.and_then(|e| e.as_deref())))
+    };
+    DayVerification {
+        result,
+        part1: verdict(&result.part1, expected.map(|e| &e.part1)),
+        part2: verdict(&result.part2, expected.map(|e| &e.part2)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Tests that the answers in a result are checked against the answer key for its input.
+    #[test]
+    fn test_verify_result() {
+        let hash = input_hash("()())");
+        let key = AnswerKey::from_json(&json!({ hash.clone(): {"day": 1, "part1": -2} })).unwrap();
+        let result = DayResult {
+            day: 1,
+            name: "Not Quite Lisp",
+            input: String::from("<stdin>"),
+            is_default_input: false,
+            input_hash: hash,
+            part1: Some(Answer::I64(-1)),
+            part2: Some(Answer::Usize(5)),
+            input_duration: Duration::ZERO,
+            part1_duration: Some(Duration::ZERO),
+            part2_duration: Some(Duration::ZERO),
+        };
+        let verification = verify_result(&result, &key);
+        assert_eq!(
+            Some(Verdict::Fail {
+                expected: String::from("-2")
+            }),
+            verification.part1
+        );
+        assert_eq!(Some(Verdict::Missing), verification.part2);
+        // Expected answers are only used for the day they were recorded against
+        assert_eq!(None, key.get(2, &input_hash("()())")));
+    }
+
+    /// Tests that recording answers only fills in the parts without an expected answer.
+    #[test]
+    fn test_answer_key_record() {
+        let hash = input_hash("abc");
+        let mut key =
+            AnswerKey::from_json(&json!({ hash.clone(): {"day": 11, "part1": "abcdffaa"} }))
+                .unwrap();
+        let result = DayResult {
+            day: 11,
+            name: "Corporate Policy",
+            input: String::from("<stdin>"),
+            is_default_input: false,
+            input_hash: hash.clone(),
+            part1: Some(Answer::Text(String::from("zzzzzzzz"))),
+            part2: Some(Answer::Text(String::from("abcdffbb"))),
+            input_duration: Duration::ZERO,
+            part1_duration: Some(Duration::ZERO),
+            part2_duration: Some(Duration::ZERO),
+        };
+        assert_eq!(1, key.record(&result));
+        assert_eq!(
+            json!({ hash: {"day": 11, "part1": "abcdffaa", "part2": "abcdffbb"} }),
+            key.to_json()
+        );
+    }
+}
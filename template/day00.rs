@@ -1,70 +1,76 @@
 use std::fs;
-use std::time::Instant;
-
-const PROBLEM_NAME: &str = "###";
-const PROBLEM_INPUT_FILE: &str = "./input/day00.txt";
-const PROBLEM_DAY: u64 = 0;
-
-/// Processes the AOC 2015 Day 00 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2015 Day {} - \"{}\"", PROBLEM_DAY, PROBLEM_NAME);
-    println!("[+] Part 1: {}", p1_solution);
-    println!("[+] Part 2: {}", p2_solution);
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {:.2?}", input_parser_duration);
-    println!("[+] Part 1: {:.2?}", p1_duration);
-    println!("[+] Part 2: {:.2?}", p2_duration);
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+
+use crate::example::Example;
+use crate::solution::Solution;
+use crate::utils::ParseError;
+
+pub const PROBLEM_NAME: &str = "###";
+pub const PROBLEM_INPUT_FILE: &str = "./input/day00.txt";
+pub const PROBLEM_DAY: u64 = 0;
+
+/// Worked examples from the problem statement.
+pub const EXAMPLES: &[Example] = &[Example::new("###", Some("###"), Some("###"))];
+
+/// Solution for AOC 2015 Day 00.
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = String;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(raw_input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve_part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve_part2(input)
+    }
 }
 
 /// Processes the AOC 2015 Day 00 input file into the format required by the solver functions.
 /// Returned value is ###.
-fn process_input_file(filename: &str) -> String {
+pub fn process_input_file(filename: &str) -> Result<String, ParseError> {
     // Read contents of problem input file
-    let _raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input =
+        fs::read_to_string(filename).map_err(|err| ParseError::unreadable(filename, &err))?;
     // Process input file contents into data structure
+    parse_input(&raw_input).map_err(|err| err.with_file(filename))
+}
+
+/// Parses the raw AOC 2015 Day 00 input into the format required by the solver functions.
+pub fn parse_input(_raw_input: &str) -> Result<String, ParseError> {
     unimplemented!();
 }
 
 /// Solves AOC 2015 Day 00 Part 1 // ###
-fn solve_part1(_input: &String) -> String {
+pub fn solve_part1(_input: &String) -> String {
     unimplemented!();
 }
 
 /// Solves AOC 2015 Day 00 Part 2 // ###
-fn solve_part2(_input: &String) -> String {
+pub fn solve_part2(_input: &String) -> String {
     unimplemented!();
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::example::assert_examples;
 
     /// Tests the Day 00 Part 1 solver method against the actual problem solution.
     #[test]
     fn test_day00_part1_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let _solution = solve_part1(&input);
         unimplemented!();
         // assert_eq!("###", solution);
@@ -73,9 +79,15 @@ mod test {
     /// Tests the Day 00 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day00_part2_actual() {
-        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let input = process_input_file(PROBLEM_INPUT_FILE).unwrap();
         let _solution = solve_part2(&input);
         unimplemented!();
         // assert_eq!("###", solution);
     }
+
+    /// Tests the Day 00 solver methods against the worked examples from the problem statement.
+    #[test]
+    fn test_day00_examples() {
+        assert_examples::<Day00>();
+    }
 }